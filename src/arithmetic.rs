@@ -0,0 +1,385 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::*;
+
+/// A pixel component with saturating, wrapping and checked arithmetic.
+///
+/// Integers use their inherent methods of the same names, except that saturating division by zero
+/// gives the maximum, or the minimum when dividing zero or a negative number, as it does for
+/// floats. Floats are bounded by [`PixelComponent::COMPONENT_MIN`] and
+/// [`PixelComponent::COMPONENT_MAX`] rather than by their representable range:
+///
+/// - Saturating operations clamp the result into the component range, with NaN becoming the
+///   minimum.
+/// - Wrapping operations wrap a result outside the component range around by whole multiples of
+///   its length, so `0.75 + 0.5` gives `0.25`. A NaN or infinite result, such as from dividing by
+///   zero, gives NaN.
+/// - Checked operations return [`None`] if the result is outside the component range or NaN.
+pub trait ArithmeticComponent: PixelComponent {
+    /// Adds two components, saturating at the component range.
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// Subtracts two components, saturating at the component range.
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// Multiplies two components, saturating at the component range.
+    fn saturating_mul(self, rhs: Self) -> Self;
+    /// Divides two components, saturating at the component range.
+    fn saturating_div(self, rhs: Self) -> Self;
+
+    /// Adds two components, wrapping around the component range.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Subtracts two components, wrapping around the component range.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Multiplies two components, wrapping around the component range.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Divides two components, wrapping around the component range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an integer is divided by zero.
+    fn wrapping_div(self, rhs: Self) -> Self;
+
+    /// Adds two components, returning [`None`] outside the component range.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Subtracts two components, returning [`None`] outside the component range.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Multiplies two components, returning [`None`] outside the component range.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Divides two components, returning [`None`] outside the component range or when dividing
+    /// an integer by zero.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! component_methods {
+    ($int:ident, $saturating:ident, $wrapping:ident, $checked:ident) => {
+        fn $saturating(self, rhs: Self) -> Self {
+            $int::$saturating(self, rhs)
+        }
+        fn $wrapping(self, rhs: Self) -> Self {
+            $int::$wrapping(self, rhs)
+        }
+        fn $checked(self, rhs: Self) -> Option<Self> {
+            $int::$checked(self, rhs)
+        }
+    };
+    ($op:tt, $saturating:ident, $wrapping:ident, $checked:ident) => {
+        fn $saturating(self, rhs: Self) -> Self {
+            saturate(self $op rhs)
+        }
+        fn $wrapping(self, rhs: Self) -> Self {
+            wrap(self $op rhs)
+        }
+        fn $checked(self, rhs: Self) -> Option<Self> {
+            check(self $op rhs)
+        }
+    };
+}
+macro_rules! implement_integer {
+    ($int:ident) => {
+        impl ArithmeticComponent for $int {
+            component_methods!($int, saturating_add, wrapping_add, checked_add);
+            component_methods!($int, saturating_sub, wrapping_sub, checked_sub);
+            component_methods!($int, saturating_mul, wrapping_mul, checked_mul);
+
+            fn saturating_div(self, rhs: Self) -> Self {
+                if rhs != 0 {
+                    $int::saturating_div(self, rhs)
+                } else if self > 0 {
+                    $int::MAX
+                } else {
+                    $int::MIN
+                }
+            }
+            fn wrapping_div(self, rhs: Self) -> Self {
+                $int::wrapping_div(self, rhs)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                $int::checked_div(self, rhs)
+            }
+        }
+    };
+}
+macro_rules! implement_float {
+    ($float:ty) => {
+        impl ArithmeticComponent for $float {
+            component_methods!(+, saturating_add, wrapping_add, checked_add);
+            component_methods!(-, saturating_sub, wrapping_sub, checked_sub);
+            component_methods!(*, saturating_mul, wrapping_mul, checked_mul);
+            component_methods!(/, saturating_div, wrapping_div, checked_div);
+        }
+    };
+}
+
+fn saturate<T>(x: T) -> T
+where
+    T: PixelComponent,
+{
+    if x > T::COMPONENT_MAX {
+        T::COMPONENT_MAX
+    } else if x >= T::COMPONENT_MIN {
+        x
+    } else {
+        T::COMPONENT_MIN
+    }
+}
+fn wrap<T>(x: T) -> T
+where
+    T: PixelComponent,
+{
+    if (T::COMPONENT_MIN..=T::COMPONENT_MAX).contains(&x) {
+        return x;
+    }
+
+    let range = T::COMPONENT_MAX - T::COMPONENT_MIN;
+    let wrapped = (x - T::COMPONENT_MIN) % range;
+    if wrapped < T::zero() {
+        wrapped + range + T::COMPONENT_MIN
+    } else {
+        wrapped + T::COMPONENT_MIN
+    }
+}
+fn check<T>(x: T) -> Option<T>
+where
+    T: PixelComponent,
+{
+    (T::COMPONENT_MIN..=T::COMPONENT_MAX)
+        .contains(&x)
+        .then_some(x)
+}
+
+implement_integer!(u8);
+implement_integer!(u16);
+implement_integer!(u32);
+implement_integer!(u64);
+implement_integer!(u128);
+implement_integer!(usize);
+implement_integer!(i8);
+implement_integer!(i16);
+implement_integer!(i32);
+implement_integer!(i64);
+implement_integer!(i128);
+implement_integer!(isize);
+implement_float!(f32);
+implement_float!(f64);
+#[cfg(feature = "half")]
+implement_float!(half::f16);
+#[cfg(feature = "half")]
+implement_float!(half::bf16);
+
+/// The right-hand side of arithmetic on a pixel of type `P`, which is either another `P` or a
+/// single component applied to every color component.
+///
+/// Arithmetic only applies to the color components, the alpha component of the left-hand side is
+/// always kept unchanged. This keeps operations such as brightening or scaling a color from
+/// changing its opacity, use [`Pixel::map_components()`] to include the alpha component.
+///
+/// The [`Add`], [`Sub`], [`Mul`] and [`Div`] operators and their assigning versions follow the
+/// behavior of the component type's own operators, so integer overflow panics in debug builds and
+/// integer division by zero always panics. See [`PixelArithmetic`] for the saturating, wrapping and
+/// checked versions.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let a = Rgba::<u8> { r: 10, g: 20, b: 30, a: 40 };
+/// let b = Rgba::<u8> { r: 1, g: 2, b: 3, a: 4 };
+///
+/// assert_eq!(a + b, Rgba { r: 11, g: 22, b: 33, a: 40 });
+/// assert_eq!(a - b, Rgba { r: 9, g: 18, b: 27, a: 40 });
+/// assert_eq!(a * 2, Rgba { r: 20, g: 40, b: 60, a: 40 });
+/// assert_eq!(a / 10, Rgba { r: 1, g: 2, b: 3, a: 40 });
+///
+/// let mut gray = Gray::<f32> { gray: 0.25 };
+/// gray *= 2.0;
+/// assert_eq!(gray, Gray { gray: 0.5 });
+/// ```
+pub trait Operand<P>
+where
+    P: Pixel,
+{
+    /// Returns the component to combine with the color component at `index`.
+    fn color(&self, index: usize) -> P::Component;
+}
+
+/// Combines the color components of `lhs` with those of `rhs` using `f`.
+fn combine<P>(
+    lhs: P,
+    rhs: impl Operand<P>,
+    mut f: impl FnMut(P::Component, P::Component) -> P::Component,
+) -> P
+where
+    P: Pixel,
+{
+    let mut index = 0;
+    lhs.map_colors(|color| {
+        let result = f(color, rhs.color(index));
+        index += 1;
+        result
+    })
+}
+/// Combines the color components of `lhs` with those of `rhs` using `f`, returning [`None`] if `f`
+/// does for any of them.
+fn checked_combine<P>(
+    lhs: P,
+    rhs: impl Operand<P>,
+    mut f: impl FnMut(P::Component, P::Component) -> Option<P::Component>,
+) -> Option<P>
+where
+    P: Pixel,
+{
+    let mut failed = false;
+    let result = combine(lhs, rhs, |lhs, rhs| {
+        f(lhs, rhs).unwrap_or_else(|| {
+            failed = true;
+            lhs
+        })
+    });
+    (!failed).then_some(result)
+}
+
+/// Saturating, wrapping and checked arithmetic on the color components of pixels.
+///
+/// Like the arithmetic operators, the right-hand side can be a pixel of the same type or a single
+/// component and the alpha component of the left-hand side is kept unchanged, see [`Operand`].
+/// See [`ArithmeticComponent`] for how the component range bounds each operation.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let pixel = Rgba::<u8> { r: 200, g: 100, b: 0, a: 255 };
+///
+/// assert_eq!(pixel.saturating_add(100), Rgba { r: 255, g: 200, b: 100, a: 255 });
+/// assert_eq!(pixel.wrapping_add(100), Rgba { r: 44, g: 200, b: 100, a: 255 });
+/// assert_eq!(pixel.checked_add(100), None);
+/// assert_eq!(pixel.checked_sub(pixel), Some(Rgba { r: 0, g: 0, b: 0, a: 255 }));
+/// assert_eq!(pixel.saturating_div(0), Rgba { r: 255, g: 255, b: 0, a: 255 });
+/// assert_eq!(pixel.checked_div(0), None);
+///
+/// let pixel = Rgb::<f32> { r: 0.75, g: 0.5, b: 0.25 };
+///
+/// assert_eq!(pixel.saturating_mul(2.0), Rgb { r: 1.0, g: 1.0, b: 0.5 });
+/// assert_eq!(pixel.wrapping_add(0.5), Rgb { r: 0.25, g: 1.0, b: 0.75 });
+/// assert_eq!(pixel.checked_sub(0.5), None);
+/// assert_eq!(pixel.saturating_div(0.0), Rgb { r: 1.0, g: 1.0, b: 1.0 });
+/// assert!(pixel.wrapping_div(0.0).r.is_nan());
+/// ```
+pub trait PixelArithmetic: Pixel<Component: ArithmeticComponent> {
+    /// Adds to the color components, saturating at the component range.
+    fn saturating_add(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::saturating_add)
+    }
+    /// Subtracts from the color components, saturating at the component range.
+    fn saturating_sub(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::saturating_sub)
+    }
+    /// Multiplies the color components, saturating at the component range.
+    fn saturating_mul(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::saturating_mul)
+    }
+    /// Divides the color components, saturating at the component range.
+    fn saturating_div(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::saturating_div)
+    }
+
+    /// Adds to the color components, wrapping around the component range.
+    fn wrapping_add(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::wrapping_add)
+    }
+    /// Subtracts from the color components, wrapping around the component range.
+    fn wrapping_sub(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::wrapping_sub)
+    }
+    /// Multiplies the color components, wrapping around the component range.
+    fn wrapping_mul(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::wrapping_mul)
+    }
+    /// Divides the color components, wrapping around the component range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an integer component is divided by zero.
+    fn wrapping_div(self, rhs: impl Operand<Self>) -> Self {
+        combine(self, rhs, ArithmeticComponent::wrapping_div)
+    }
+
+    /// Adds to the color components, returning [`None`] if any leaves the component range.
+    fn checked_add(self, rhs: impl Operand<Self>) -> Option<Self> {
+        checked_combine(self, rhs, ArithmeticComponent::checked_add)
+    }
+    /// Subtracts from the color components, returning [`None`] if any leaves the component
+    /// range.
+    fn checked_sub(self, rhs: impl Operand<Self>) -> Option<Self> {
+        checked_combine(self, rhs, ArithmeticComponent::checked_sub)
+    }
+    /// Multiplies the color components, returning [`None`] if any leaves the component range.
+    fn checked_mul(self, rhs: impl Operand<Self>) -> Option<Self> {
+        checked_combine(self, rhs, ArithmeticComponent::checked_mul)
+    }
+    /// Divides the color components, returning [`None`] if any leaves the component range or an
+    /// integer is divided by zero.
+    fn checked_div(self, rhs: impl Operand<Self>) -> Option<Self> {
+        checked_combine(self, rhs, ArithmeticComponent::checked_div)
+    }
+}
+
+macro_rules! implement_operator {
+    ($name:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<T, R> $trait<R> for $name<T>
+        where
+            T: PixelComponent,
+            R: Operand<Self>,
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: R) -> Self {
+                combine(self, rhs, |lhs, rhs| lhs $op rhs)
+            }
+        }
+        impl<T, R> $assign_trait<R> for $name<T>
+        where
+            T: PixelComponent,
+            R: Operand<Self>,
+        {
+            fn $assign_method(&mut self, rhs: R) {
+                *self = combine(*self, rhs, |lhs, rhs| lhs $op rhs);
+            }
+        }
+    };
+}
+macro_rules! implement_arithmetic {
+    ($name:ident) => {
+        impl<T> Operand<$name<T>> for T
+        where
+            T: PixelComponent,
+        {
+            fn color(&self, _: usize) -> T {
+                *self
+            }
+        }
+        impl<T> Operand<$name<T>> for $name<T>
+        where
+            T: PixelComponent,
+        {
+            fn color(&self, index: usize) -> T {
+                self.color_array()[index]
+            }
+        }
+
+        implement_operator!($name, Add, add, AddAssign, add_assign, +);
+        implement_operator!($name, Sub, sub, SubAssign, sub_assign, -);
+        implement_operator!($name, Mul, mul, MulAssign, mul_assign, *);
+        implement_operator!($name, Div, div, DivAssign, div_assign, /);
+
+        impl<T> PixelArithmetic for $name<T> where T: ArithmeticComponent {}
+    };
+}
+
+implement_arithmetic!(Rgb);
+implement_arithmetic!(Bgr);
+implement_arithmetic!(Rgba);
+implement_arithmetic!(Argb);
+implement_arithmetic!(Bgra);
+implement_arithmetic!(Abgr);
+implement_arithmetic!(Gray);
+implement_arithmetic!(GrayAlpha);
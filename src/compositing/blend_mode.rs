@@ -0,0 +1,197 @@
+use crate::*;
+
+/// The blend modes from the W3C Compositing and Blending Level 1 specification.
+///
+/// Both pixels are normalized to [`Rgba<f64>`] using [`FromPixelCommon`], blended, then
+/// composited source-over onto the backdrop as described in the specification, before being
+/// converted back. Pixels without an alpha component are treated as fully opaque.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let source = Rgb::<u8> { r: 255, g: 128, b: 0 };
+/// let backdrop = Rgb::<u8> { r: 128, g: 128, b: 128 };
+///
+/// assert_eq!(BlendMode::Multiply.blend(source, backdrop), Rgb { r: 128, g: 64, b: 0 });
+/// assert_eq!(BlendMode::Screen.blend(source, backdrop), Rgb { r: 255, g: 192, b: 128 });
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BlendMode {
+    /// Selects the source color.
+    Normal,
+    /// Multiplies the source and backdrop colors.
+    Multiply,
+    /// Multiplies the complements of the source and backdrop colors, then complements the result.
+    Screen,
+    /// Multiplies or screens the colors depending on the backdrop color.
+    Overlay,
+    /// Selects the darker of the source and backdrop colors.
+    Darken,
+    /// Selects the lighter of the source and backdrop colors.
+    Lighten,
+    /// Brightens the backdrop color to reflect the source color.
+    ColorDodge,
+    /// Darkens the backdrop color to reflect the source color.
+    ColorBurn,
+    /// Multiplies or screens the colors depending on the source color.
+    HardLight,
+    /// Darkens or lightens the colors depending on the source color.
+    SoftLight,
+    /// Subtracts the darker of the two colors from the lighter color.
+    Difference,
+    /// Like [`BlendMode::Difference`] but with lower contrast.
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the backdrop.
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the backdrop.
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the backdrop.
+    Color,
+    /// The luminosity of the source with the hue and saturation of the backdrop.
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Blends the `source` pixel with the `backdrop` pixel.
+    pub fn blend<P>(self, source: P, backdrop: P) -> P
+    where
+        P: FromPixelCommon<Rgba<f64>>,
+        Rgba<f64>: FromPixelCommon<P>,
+    {
+        let source = Rgba::<f64>::from_pixel_common(source);
+        let backdrop = Rgba::<f64>::from_pixel_common(backdrop);
+
+        let source_colors = [source.r, source.g, source.b];
+        let backdrop_colors = [backdrop.r, backdrop.g, backdrop.b];
+
+        let blended = match self {
+            BlendMode::Hue => set_lum(
+                set_sat(source_colors, sat(backdrop_colors)),
+                lum(backdrop_colors),
+            ),
+            BlendMode::Saturation => set_lum(
+                set_sat(backdrop_colors, sat(source_colors)),
+                lum(backdrop_colors),
+            ),
+            BlendMode::Color => set_lum(source_colors, lum(backdrop_colors)),
+            BlendMode::Luminosity => set_lum(backdrop_colors, lum(source_colors)),
+            separable => {
+                let mut blended = [0.0; 3];
+                for i in 0..3 {
+                    blended[i] = separable.blend_separable(backdrop_colors[i], source_colors[i]);
+                }
+                blended
+            }
+        };
+
+        let alpha = source.a + backdrop.a * (1.0 - source.a);
+        let mut colors = [0.0; 3];
+        if alpha > 0.0 {
+            for i in 0..3 {
+                let source_color = (1.0 - backdrop.a) * source_colors[i] + backdrop.a * blended[i];
+                colors[i] = (source.a * source_color
+                    + (1.0 - source.a) * backdrop.a * backdrop_colors[i])
+                    / alpha;
+            }
+        }
+
+        P::from_pixel_common(Rgba {
+            r: colors[0],
+            g: colors[1],
+            b: colors[2],
+            a: alpha,
+        })
+    }
+
+    fn blend_separable(self, backdrop: f64, source: f64) -> f64 {
+        match self {
+            BlendMode::Multiply => backdrop * source,
+            BlendMode::Screen => backdrop + source - backdrop * source,
+            BlendMode::Overlay => BlendMode::HardLight.blend_separable(source, backdrop),
+            BlendMode::Darken => backdrop.min(source),
+            BlendMode::Lighten => backdrop.max(source),
+            BlendMode::ColorDodge => {
+                if backdrop == 0.0 {
+                    0.0
+                } else if source >= 1.0 {
+                    1.0
+                } else {
+                    (backdrop / (1.0 - source)).min(1.0)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if backdrop >= 1.0 {
+                    1.0
+                } else if source == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - backdrop) / source).min(1.0)
+                }
+            }
+            BlendMode::HardLight => {
+                if source <= 0.5 {
+                    BlendMode::Multiply.blend_separable(backdrop, 2.0 * source)
+                } else {
+                    BlendMode::Screen.blend_separable(backdrop, 2.0 * source - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if source <= 0.5 {
+                    backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+                } else {
+                    let d = if backdrop <= 0.25 {
+                        ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                    } else {
+                        libm::sqrt(backdrop)
+                    };
+                    backdrop + (2.0 * source - 1.0) * (d - backdrop)
+                }
+            }
+            BlendMode::Difference => (backdrop - source).abs(),
+            BlendMode::Exclusion => backdrop + source - 2.0 * backdrop * source,
+            _ => source,
+        }
+    }
+}
+
+fn lum(c: [f64; 3]) -> f64 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+fn clip_color(c: [f64; 3]) -> [f64; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+
+    c.map(|c| {
+        let c = if n < 0.0 {
+            l + (c - l) * l / (l - n)
+        } else {
+            c
+        };
+        if x > 1.0 {
+            l + (c - l) * (1.0 - l) / (x - l)
+        } else {
+            c
+        }
+    })
+}
+fn set_lum(c: [f64; 3], l: f64) -> [f64; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|c| c + d))
+}
+fn sat(c: [f64; 3]) -> f64 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+fn set_sat(c: [f64; 3], s: f64) -> [f64; 3] {
+    let mut order = [0, 1, 2];
+    order.sort_unstable_by(|&i, &j| c[i].total_cmp(&c[j]));
+    let [min, mid, max] = order;
+
+    let mut result = [0.0; 3];
+    if c[max] > c[min] {
+        result[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        result[max] = s;
+    }
+    result
+}
@@ -0,0 +1,2 @@
+pub mod blend_mode;
+pub mod porter_duff;
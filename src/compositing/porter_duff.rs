@@ -0,0 +1,115 @@
+use num_traits::Zero;
+
+use crate::from_pixel_common::enlargeable::{from_offset, offset, range, sum_of_products_divide};
+use crate::*;
+
+/// The Porter-Duff compositing operators, which combine a source pixel with a destination pixel
+/// according to how much each one covers the other.
+///
+/// Pixels without an alpha component are treated as fully opaque. Integer components are rounded
+/// to the nearest value, with the whole calculation for each component done in
+/// [`Enlargeable::Larger`] so that only one rounding step occurs.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let source = Rgba::<u8> { r: 255, g: 0, b: 0, a: 128 };
+/// let destination = Rgb::<u8> { r: 0, g: 0, b: 255 };
+///
+/// assert_eq!(
+///     PorterDuff::Over.composite(source, destination.with_alpha()),
+///     Rgba { r: 128, g: 0, b: 127, a: 255 }
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PorterDuff {
+    /// The source is placed over the destination.
+    Over,
+    /// The part of the source inside the destination replaces the destination.
+    In,
+    /// The part of the source outside the destination replaces the destination.
+    Out,
+    /// The part of the source inside the destination is placed over the destination.
+    Atop,
+    /// The parts of the source and destination which do not overlap.
+    Xor,
+    /// The source and destination are added together.
+    Plus,
+}
+
+impl PorterDuff {
+    /// Composites a straight-alpha `source` pixel with a straight-alpha `destination` pixel.
+    ///
+    /// Both pixels are given an alpha component using [`WithAlpha`] then premultiplied before
+    /// being composited with [`PorterDuff::composite_premultiplied()`].
+    pub fn composite<P>(self, source: P, destination: P) -> P::WithAlpha
+    where
+        P: WithAlpha<WithAlpha: Pixel<Component: Enlargeable>>,
+    {
+        let source = Premultiplied::premultiply(source.with_alpha());
+        let destination = Premultiplied::premultiply(destination.with_alpha());
+
+        self.composite_premultiplied(source, destination)
+            .unpremultiply()
+    }
+
+    /// Composites a premultiplied `source` pixel with a premultiplied `destination` pixel.
+    ///
+    /// Color components larger than their alpha component are invalid for premultiplied pixels
+    /// and are clamped to the alpha component first.
+    pub fn composite_premultiplied<P>(
+        self,
+        source: Premultiplied<P>,
+        destination: Premultiplied<P>,
+    ) -> Premultiplied<P>
+    where
+        P: Pixel<Component: Enlargeable>,
+    {
+        let zero = <P::Component as Enlargeable>::Larger::zero();
+        let range = range::<P::Component>();
+
+        let source_alpha = source.alpha().map_or(range, offset);
+        let destination_alpha = destination.alpha().map_or(range, offset);
+
+        let (source_factor, destination_factor) = match self {
+            PorterDuff::Over => (range, range - source_alpha),
+            PorterDuff::In => (destination_alpha, zero),
+            PorterDuff::Out => (range - destination_alpha, zero),
+            PorterDuff::Atop => (destination_alpha, range - source_alpha),
+            PorterDuff::Xor => (range - destination_alpha, range - source_alpha),
+            PorterDuff::Plus => (range, range),
+        };
+
+        let mut destination_components = destination.component_array().into_iter();
+        Premultiplied::from_components(source.component_array().into_iter().map(|source| {
+            let destination = destination_components.next().unwrap();
+
+            let source = min(offset(source), source_alpha);
+            let destination = min(offset(destination), destination_alpha);
+
+            if self == PorterDuff::Plus {
+                from_offset(source + destination)
+            } else {
+                from_offset(sum_of_products_divide::<P::Component>(
+                    source,
+                    source_factor,
+                    destination,
+                    destination_factor,
+                    range,
+                ))
+            }
+        }))
+    }
+}
+
+fn min<L>(x: L, y: L) -> L
+where
+    L: PartialOrd,
+{
+    if x > y {
+        y
+    } else {
+        x
+    }
+}
@@ -0,0 +1,302 @@
+use num_traits::Float;
+
+use crate::*;
+
+/// A metric for how different two pixels look, used by [`ColorDistance::distance()`].
+///
+/// Pixels are converted to the metric's color space using [`FromPixelCommon`], so any pixel of
+/// the RGB family can be compared. The alpha component is ignored. Pixels which are already in
+/// Lab or Oklab can be compared directly using the methods on [`Lab`] and [`Oklab`].
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let red = Rgb::<u8> { r: 255, g: 0, b: 0 };
+/// let dark_red = Rgb::<u8> { r: 200, g: 0, b: 0 };
+///
+/// assert_eq!(ColorDistance::Euclidean.distance(red, red), 0.0);
+/// assert!((ColorDistance::Euclidean.distance(red, dark_red) - 55.0 / 255.0).abs() < 1e-12);
+///
+/// let a = Bgra::<f32> { b: 0.2, g: 0.4, r: 0.6, a: 1.0 };
+/// let b = Bgra::<f32> { b: 0.2, g: 0.4, r: 0.6, a: 0.0 };
+/// assert_eq!(ColorDistance::Ciede2000.distance(a, b), 0.0);
+///
+/// // Every metric agrees on the order of these colors.
+/// let orange = Rgb::<u8> { r: 255, g: 128, b: 0 };
+/// for metric in [
+///     ColorDistance::Euclidean,
+///     ColorDistance::Cie76,
+///     ColorDistance::Cie94,
+///     ColorDistance::Ciede2000,
+///     ColorDistance::Oklab,
+/// ] {
+///     assert!(metric.distance(red, dark_red) < metric.distance(red, orange));
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ColorDistance {
+    /// The Euclidean distance between [`Rgb<f64>`] pixels, with components from `0.0` to `1.0`.
+    Euclidean,
+    /// [`Lab::delta_e_76()`].
+    Cie76,
+    /// [`Lab::delta_e_94()`], treating the first pixel as the reference.
+    Cie94,
+    /// [`Lab::delta_e_2000()`].
+    #[default]
+    Ciede2000,
+    /// [`Oklab::delta_e()`].
+    Oklab,
+}
+
+impl ColorDistance {
+    /// Returns the distance between `a` and `b`, which is zero for identical colors.
+    pub fn distance<P>(self, a: P, b: P) -> f64
+    where
+        Rgb<f64>: FromPixelCommon<P>,
+        Lab<f64>: FromPixelCommon<P>,
+        Oklab<f64>: FromPixelCommon<P>,
+    {
+        match self {
+            ColorDistance::Euclidean => {
+                let a = Rgb::<f64>::from_pixel_common(a);
+                let b = Rgb::<f64>::from_pixel_common(b);
+                euclidean([a.r, a.g, a.b], [b.r, b.g, b.b])
+            }
+            ColorDistance::Cie76 => Lab::from_pixel_common(a).delta_e_76(Lab::from_pixel_common(b)),
+            ColorDistance::Cie94 => Lab::from_pixel_common(a).delta_e_94(Lab::from_pixel_common(b)),
+            ColorDistance::Ciede2000 => {
+                Lab::from_pixel_common(a).delta_e_2000(Lab::from_pixel_common(b))
+            }
+            ColorDistance::Oklab => {
+                Oklab::<f64>::from_pixel_common(a).delta_e(Oklab::from_pixel_common(b))
+            }
+        }
+    }
+}
+
+impl<T> Lab<T>
+where
+    T: PixelComponent + Float,
+{
+    /// Returns the CIE 1976 color difference, which is the Euclidean distance in Lab.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let a = Lab::<f32> { l: 50.0, a: 0.0, b: 0.0 };
+    /// let b = Lab::<f32> { l: 53.0, a: 4.0, b: 0.0 };
+    ///
+    /// assert_eq!(a.delta_e_76(b), 5.0);
+    /// ```
+    pub fn delta_e_76(self, other: Self) -> f64 {
+        euclidean(lab(self), lab(other))
+    }
+
+    /// Returns the CIE 1994 color difference using the graphic arts weights, with `self` as the
+    /// reference color.
+    ///
+    /// Unlike the other color differences this is not symmetric, as the chroma and hue weights
+    /// depend on the chroma of the reference color.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let reference = Lab::<f64> { l: 50.0, a: 2.6772, b: -79.7751 };
+    /// let sample = Lab::<f64> { l: 50.0, a: 0.0, b: -82.7485 };
+    ///
+    /// assert!((reference.delta_e_94(sample) - 1.3950).abs() < 5e-5);
+    /// assert!((sample.delta_e_94(reference) - 1.3653).abs() < 5e-5);
+    /// ```
+    pub fn delta_e_94(self, other: Self) -> f64 {
+        const K1: f64 = 0.045;
+        const K2: f64 = 0.015;
+
+        let [l1, a1, b1] = lab(self);
+        let [l2, a2, b2] = lab(other);
+        let c1 = libm::hypot(a1, b1);
+        let c2 = libm::hypot(a2, b2);
+
+        let delta_l = l1 - l2;
+        let delta_c = c1 - c2;
+        // Rounding can make the squared hue difference slightly negative for equal hues.
+        let delta_h_squared =
+            ((a1 - a2) * (a1 - a2) + (b1 - b2) * (b1 - b2) - delta_c * delta_c).max(0.0);
+
+        let s_c = 1.0 + K1 * c1;
+        let s_h = 1.0 + K2 * c1;
+
+        libm::sqrt(
+            delta_l * delta_l + (delta_c / s_c) * (delta_c / s_c) + delta_h_squared / (s_h * s_h),
+        )
+    }
+
+    /// Returns the CIEDE2000 color difference.
+    ///
+    /// # Examples
+    /// Test vectors from Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula:
+    /// Implementation Notes, Supplementary Test Data, and Mathematical Observations" (2005).
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let pairs = [
+    ///     ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+    ///     ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+    ///     ([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+    ///     ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+    ///     ([50.0, -1.1848, -84.8006], [50.0, 0.0, -82.7485], 1.0000),
+    ///     ([50.0, -0.9009, -85.5211], [50.0, 0.0, -82.7485], 1.0000),
+    ///     ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+    ///     ([50.0, -1.0, 2.0], [50.0, 0.0, 0.0], 2.3669),
+    ///     ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0009], 7.1792),
+    ///     ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0010], 7.1792),
+    ///     ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0011], 7.2195),
+    ///     ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0012], 7.2195),
+    ///     ([50.0, -0.0010, 2.4900], [50.0, 0.0009, -2.4900], 4.8045),
+    ///     ([50.0, -0.0010, 2.4900], [50.0, 0.0010, -2.4900], 4.8045),
+    ///     ([50.0, -0.0010, 2.4900], [50.0, 0.0011, -2.4900], 4.7461),
+    ///     ([50.0, 2.5, 0.0], [50.0, 0.0, -2.5], 4.3065),
+    ///     ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+    ///     ([50.0, 2.5, 0.0], [61.0, -5.0, 29.0], 22.8977),
+    ///     ([50.0, 2.5, 0.0], [56.0, -27.0, -3.0], 31.9030),
+    ///     ([50.0, 2.5, 0.0], [58.0, 24.0, 15.0], 19.4535),
+    ///     ([50.0, 2.5, 0.0], [50.0, 3.1736, 0.5854], 1.0000),
+    ///     ([50.0, 2.5, 0.0], [50.0, 3.2972, 0.0], 1.0000),
+    ///     ([50.0, 2.5, 0.0], [50.0, 1.8634, 0.5757], 1.0000),
+    ///     ([50.0, 2.5, 0.0], [50.0, 3.2592, 0.3350], 1.0000),
+    ///     ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+    ///     ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+    ///     ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+    ///     ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+    ///     ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+    ///     ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+    ///     ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+    ///     ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+    ///     ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+    ///     ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+    /// ];
+    ///
+    /// for ([l1, a1, b1], [l2, a2, b2], expected) in pairs {
+    ///     let first = Lab::<f64> { l: l1, a: a1, b: b1 };
+    ///     let second = Lab::<f64> { l: l2, a: a2, b: b2 };
+    ///
+    ///     assert!((first.delta_e_2000(second) - expected).abs() < 5e-5);
+    ///     assert!((second.delta_e_2000(first) - expected).abs() < 5e-5);
+    /// }
+    /// ```
+    pub fn delta_e_2000(self, other: Self) -> f64 {
+        // 25^7, the chroma at which the a* axis is stretched by half as much as for neutrals.
+        const CHROMA_WEIGHT: f64 = 6103515625.0;
+
+        let [l1, a1, b1] = lab(self);
+        let [l2, a2, b2] = lab(other);
+
+        let mean_c = (libm::hypot(a1, b1) + libm::hypot(a2, b2)) / 2.0;
+        let mean_c7 = libm::pow(mean_c, 7.0);
+        let g = 0.5 * (1.0 - libm::sqrt(mean_c7 / (mean_c7 + CHROMA_WEIGHT)));
+
+        let a1 = (1.0 + g) * a1;
+        let a2 = (1.0 + g) * a2;
+        let c1 = libm::hypot(a1, b1);
+        let c2 = libm::hypot(a2, b2);
+        let h1 = hue_degrees(a1, b1);
+        let h2 = hue_degrees(a2, b2);
+        let neutral = c1 * c2 == 0.0;
+
+        let delta_l = l2 - l1;
+        let delta_c = c2 - c1;
+        let delta_h = match h2 - h1 {
+            _ if neutral => 0.0,
+            delta if delta > 180.0 => delta - 360.0,
+            delta if delta < -180.0 => delta + 360.0,
+            delta => delta,
+        };
+        let delta_h = 2.0 * libm::sqrt(c1 * c2) * libm::sin((delta_h / 2.0).to_radians());
+
+        let mean_l = (l1 + l2) / 2.0;
+        let mean_c = (c1 + c2) / 2.0;
+        let mean_h = match h1 + h2 {
+            sum if neutral => sum,
+            sum if (h1 - h2).abs() <= 180.0 => sum / 2.0,
+            sum if sum < 360.0 => (sum + 360.0) / 2.0,
+            sum => (sum - 360.0) / 2.0,
+        };
+
+        let cos = |degrees: f64| libm::cos(degrees.to_radians());
+        let t = 1.0 - 0.17 * cos(mean_h - 30.0)
+            + 0.24 * cos(2.0 * mean_h)
+            + 0.32 * cos(3.0 * mean_h + 6.0)
+            - 0.20 * cos(4.0 * mean_h - 63.0);
+        let delta_theta = 30.0 * libm::exp(-libm::pow((mean_h - 275.0) / 25.0, 2.0));
+        let mean_c7 = libm::pow(mean_c, 7.0);
+        let r_c = 2.0 * libm::sqrt(mean_c7 / (mean_c7 + CHROMA_WEIGHT));
+        let r_t = -libm::sin((2.0 * delta_theta).to_radians()) * r_c;
+
+        let l_offset = (mean_l - 50.0) * (mean_l - 50.0);
+        let s_l = 1.0 + 0.015 * l_offset / libm::sqrt(20.0 + l_offset);
+        let s_c = 1.0 + 0.045 * mean_c;
+        let s_h = 1.0 + 0.015 * mean_c * t;
+
+        let l = delta_l / s_l;
+        let c = delta_c / s_c;
+        let h = delta_h / s_h;
+        libm::sqrt(l * l + c * c + h * h + r_t * c * h)
+    }
+}
+
+impl<T> Oklab<T>
+where
+    T: PixelComponent + Float,
+{
+    /// Returns the Oklab color difference, which is the Euclidean distance in Oklab.
+    ///
+    /// Oklab lightness ranges from `0.0` to `1.0` rather than to `100.0`, so differences are
+    /// around a hundredth of the CIE color differences.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let black = Oklab::<f32> { l: 0.0, a: 0.0, b: 0.0 };
+    /// let white = Oklab::<f32>::from_pixel_common(Rgb::<u8> { r: 255, g: 255, b: 255 });
+    ///
+    /// assert!((black.delta_e(white) - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn delta_e(self, other: Self) -> f64 {
+        let [l1, a1, b1] = self.color_array().map(cast);
+        let [l2, a2, b2] = other.color_array().map(cast);
+        euclidean([l1, a1, b1], [l2, a2, b2])
+    }
+}
+
+fn cast<T>(x: T) -> f64
+where
+    T: Float,
+{
+    num_traits::cast(x).unwrap()
+}
+fn lab<T>(pixel: Lab<T>) -> [f64; 3]
+where
+    T: PixelComponent + Float,
+{
+    [pixel.l, pixel.a, pixel.b].map(cast)
+}
+fn euclidean(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let [x, y, z] = [0, 1, 2].map(|i| a[i] - b[i]);
+    libm::sqrt(x * x + y * y + z * z)
+}
+/// Returns the hue angle from `0.0` to `360.0` degrees, which is zero for neutral colors.
+fn hue_degrees(a: f64, b: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    let hue = libm::atan2(b, a).to_degrees();
+    if hue < 0.0 {
+        hue + 360.0
+    } else {
+        hue
+    }
+}
@@ -0,0 +1,451 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use num_traits::PrimInt;
+
+use crate::palette::{indexed, nearest, normalize, squared_distance, Point};
+use crate::*;
+
+/// How to dither pixels when reducing them to fewer colors, which hides banding by trading it for
+/// fine noise.
+///
+/// Dithering works on images stored row by row in slices, given the width of the image. Ordered
+/// dithering offsets each pixel by a threshold depending only on its position, while error
+/// diffusion carries the error of each pixel over to the pixels to its right and below. Every
+/// method is deterministic, with [`Dither::BlueNoise`] giving the same result for the same seed.
+///
+/// Only the color components are dithered, so the alpha component is rounded as usual.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// // A gradient so shallow that rounding bands it into a single step.
+/// let width = 64;
+/// let gradient: Vec<_> = (0..width * 8)
+///     .map(|i| {
+///         let gray = (128.0 + (i % width) as f32 / width as f32) / 255.0;
+///         Rgb::<f32> { r: gray, g: gray, b: gray }
+///     })
+///     .collect();
+/// let expected = gradient.iter().map(|pixel| pixel.r * 255.0).sum::<f32>() / gradient.len() as f32;
+///
+/// for dither in [
+///     Dither::Bayer,
+///     Dither::BlueNoise { seed: 7 },
+///     Dither::FloydSteinberg,
+///     Dither::Atkinson,
+/// ] {
+///     let mut dithered = vec![Rgb::<u8>::default(); gradient.len()];
+///     dither.convert_slice(&gradient, &mut dithered, width).unwrap();
+///
+///     let mean = dithered.iter().map(|pixel| f32::from(pixel.r)).sum::<f32>() / dithered.len() as f32;
+///     assert!((mean - expected).abs() < 0.02);
+///     assert!(dithered.iter().all(|pixel| pixel.r == 128 || pixel.r == 129));
+/// }
+///
+/// // Blue noise is reproducible for the same seed.
+/// let mut first = vec![Rgb::<u8>::default(); gradient.len()];
+/// let mut second = vec![Rgb::<u8>::default(); gradient.len()];
+/// Dither::BlueNoise { seed: 1 }.convert_slice(&gradient, &mut first, width).unwrap();
+/// Dither::BlueNoise { seed: 1 }.convert_slice(&gradient, &mut second, width).unwrap();
+/// assert_eq!(first, second);
+///
+/// Dither::BlueNoise { seed: 2 }.convert_slice(&gradient, &mut second, width).unwrap();
+/// assert_ne!(first, second);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Dither {
+    /// Ordered dithering with an 8 by 8 Bayer matrix, which is fast but gives a visible
+    /// cross-hatch pattern.
+    Bayer,
+    /// Ordered dithering with a 32 by 32 blue noise texture, which looks like fine grain.
+    ///
+    /// The texture is generated from the seed using the void-and-cluster algorithm for each call,
+    /// which takes a few milliseconds.
+    BlueNoise {
+        /// The seed for the blue noise texture.
+        seed: u64,
+    },
+    /// Floyd–Steinberg error diffusion.
+    #[default]
+    FloydSteinberg,
+    /// Atkinson error diffusion, which only carries over three quarters of the error, giving more
+    /// contrast at the cost of detail in highlights and shadows.
+    Atkinson,
+}
+
+impl Dither {
+    /// Converts each pixel in `source` into the same position in `destination`, reducing the
+    /// components to `S` with dithering.
+    ///
+    /// `source` is an image `width` pixels wide stored row by row. Ordered dithering only applies
+    /// to integer components, as float components have no fixed step between values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices are not
+    /// the same length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    pub fn convert_slice<P, S>(
+        self,
+        source: &[P],
+        destination: &mut [P::SelfType<S>],
+        width: usize,
+    ) -> Result<(), LengthMismatchError>
+    where
+        P: Pixel,
+        S: PixelComponent + FromComponentCommon<f64>,
+        f64: FromComponentCommon<P::Component> + FromComponentCommon<S>,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        self.dither(
+            source,
+            destination,
+            width,
+            step::<S>(),
+            |point: Point<P>| {
+                let components = point.component_array().into_iter();
+                let pixel =
+                    P::SelfType::<S>::from_components(components.map(S::from_component_common));
+                let components = pixel.component_array().into_iter();
+                let quantized =
+                    Point::<P>::from_components(components.map(f64::from_component_common));
+                (pixel, quantized)
+            },
+        );
+        Ok(())
+    }
+
+    /// Replaces each pixel in `source` with the index of a color in `palette`, as in
+    /// [`Palette::quantize_slice()`] but with dithering.
+    ///
+    /// `source` is an image `width` pixels wide stored row by row. Ordered dithering offsets
+    /// pixels by up to half the average distance between the palette colors.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaletteError`] and leaves `destination` untouched if the two slices are not the
+    /// same length, the palette is empty or `I` cannot hold the index of every color.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let palette = Palette::new([Gray::<u8> { gray: 0 }, Gray::<u8> { gray: 255 }]);
+    /// let gray = [Gray::<u8> { gray: 64 }; 16 * 16];
+    ///
+    /// let mut indexed = [Indexed::<u8>::default(); 16 * 16];
+    /// palette.quantize_slice(&gray, &mut indexed).unwrap();
+    /// assert!(indexed.iter().all(|pixel| pixel.index == 0));
+    ///
+    /// // A quarter of the pixels become white.
+    /// for dither in [Dither::Bayer, Dither::BlueNoise { seed: 0 }, Dither::FloydSteinberg] {
+    ///     dither.quantize_slice(&palette, &gray, &mut indexed, 16).unwrap();
+    ///     let white = indexed.iter().filter(|pixel| pixel.index == 1).count();
+    ///     assert!(white.abs_diff(64) <= 2);
+    /// }
+    /// ```
+    pub fn quantize_slice<P, I>(
+        self,
+        palette: &Palette<P>,
+        source: &[P],
+        destination: &mut [Indexed<I>],
+        width: usize,
+    ) -> Result<(), PaletteError>
+    where
+        P: Pixel,
+        I: PrimInt,
+        P::Component: FromComponentCommon<f64>,
+        f64: FromComponentCommon<P::Component>,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        palette.check_indices::<I>()?;
+        let colors: Vec<_> = palette
+            .colors()
+            .iter()
+            .map(|color| normalize(*color))
+            .collect();
+        self.dither(source, destination, width, spacing(&colors), |point| {
+            let index = nearest(&colors, point);
+            (indexed(index), colors[index])
+        });
+        Ok(())
+    }
+
+    /// Dithers `source` into `destination` using `quantize`, which returns the quantized pixel
+    /// along with its normalized components.
+    ///
+    /// Ordered dithering offsets pixels by up to half of `spread`.
+    fn dither<P, Q>(
+        self,
+        source: &[P],
+        destination: &mut [Q],
+        width: usize,
+        spread: f64,
+        quantize: impl FnMut(Point<P>) -> (Q, Point<P>),
+    ) where
+        P: Pixel,
+        f64: FromComponentCommon<P::Component>,
+    {
+        assert!(width > 0, "width is zero");
+        match self {
+            Dither::Bayer => ordered(source, destination, width, spread, bayer, quantize),
+            Dither::BlueNoise { seed } => {
+                let texture = blue_noise(seed);
+                let threshold = |x: usize, y: usize| {
+                    texture[(y % BLUE_NOISE_SIZE) * BLUE_NOISE_SIZE + x % BLUE_NOISE_SIZE]
+                };
+                ordered(source, destination, width, spread, threshold, quantize)
+            }
+            Dither::FloydSteinberg => {
+                diffuse(source, destination, width, &FLOYD_STEINBERG, quantize)
+            }
+            Dither::Atkinson => diffuse(source, destination, width, &ATKINSON, quantize),
+        }
+    }
+}
+
+/// Returns the normalized step between neighboring values of `S`, or zero if `S` is a float.
+fn step<S>() -> f64
+where
+    S: PixelComponent + FromComponentCommon<f64>,
+    f64: FromComponentCommon<S>,
+{
+    // Only floats can represent a half exactly.
+    if f64::from_component_common(S::from_component_common(0.5)) == 0.5 {
+        return 0.0;
+    }
+    f64::from_component_common(S::COMPONENT_MIN + S::one())
+        - f64::from_component_common(S::COMPONENT_MIN)
+}
+
+/// Returns the average distance from each color to the nearest other color.
+fn spacing<Q>(colors: &[Q]) -> f64
+where
+    Q: Pixel<Component = f64>,
+{
+    if colors.len() < 2 {
+        return 0.0;
+    }
+    let total: f64 = colors
+        .iter()
+        .enumerate()
+        .map(|(i, color)| {
+            let nearest = colors
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, other)| squared_distance(*color, *other))
+                .fold(f64::INFINITY, f64::min);
+            libm::sqrt(nearest)
+        })
+        .sum();
+    total / colors.len() as f64
+}
+
+fn ordered<P, Q>(
+    source: &[P],
+    destination: &mut [Q],
+    width: usize,
+    spread: f64,
+    threshold: impl Fn(usize, usize) -> f64,
+    mut quantize: impl FnMut(Point<P>) -> (Q, Point<P>),
+) where
+    P: Pixel,
+    f64: FromComponentCommon<P::Component>,
+{
+    let rows = source.chunks(width).zip(destination.chunks_mut(width));
+    for (y, (source, destination)) in rows.enumerate() {
+        for (x, (source, destination)) in source.iter().zip(destination).enumerate() {
+            let offset = (threshold(x, y) - 0.5) * spread;
+            *destination = quantize(normalize(*source).map_colors(|color| color + offset)).0;
+        }
+    }
+}
+
+/// Returns the threshold at column `x` and row `y` of an 8 by 8 Bayer matrix.
+fn bayer(x: usize, y: usize) -> f64 {
+    // Interleaving the bits of `x ^ y` and `y` in reverse order gives the rank.
+    let rank = (0..3).fold(0, |rank, bit| {
+        (rank << 2) | ((((x ^ y) >> bit) & 1) << 1) | ((y >> bit) & 1)
+    });
+    (rank as f64 + 0.5) / 64.0
+}
+
+const BLUE_NOISE_SIZE: usize = 32;
+
+/// Generates a blue noise texture of thresholds using the void-and-cluster algorithm, from
+/// Ulichney, "The void-and-cluster method for dither array generation" (1993).
+fn blue_noise(seed: u64) -> Vec<f64> {
+    const SIZE: usize = BLUE_NOISE_SIZE;
+    const LEN: usize = SIZE * SIZE;
+    const SIGMA: f64 = 1.5;
+
+    // The energy each point adds at each offset, wrapping around the edges of the texture.
+    let kernel: Vec<f64> = (0..LEN)
+        .map(|i| {
+            let [dx, dy] = [i % SIZE, i / SIZE].map(|d| d.min(SIZE - d) as f64);
+            libm::exp(-(dx * dx + dy * dy) / (2.0 * SIGMA * SIGMA))
+        })
+        .collect();
+    let update = |energy: &mut [f64], point: usize, sign: f64| {
+        let [x, y] = [point % SIZE, point / SIZE];
+        for (row, energy) in energy.chunks_exact_mut(SIZE).enumerate() {
+            let dy = (row + SIZE - y) % SIZE;
+            let kernel = &kernel[dy * SIZE..][..SIZE];
+            // The kernel row starts at column `x`, wrapping around to the start of the row.
+            let (left, right) = energy.split_at_mut(x);
+            let (kernel_right, kernel_left) = kernel.split_at(SIZE - x);
+            for (energy, kernel) in right.iter_mut().zip(kernel_right) {
+                *energy += sign * kernel;
+            }
+            for (energy, kernel) in left.iter_mut().zip(kernel_left) {
+                *energy += sign * kernel;
+            }
+        }
+    };
+    // The tightest cluster is the set point with the most energy, returned as its position in
+    // `set`, and the largest void is the unset point with the least energy.
+    let tightest_cluster = |set: &[usize], energy: &[f64]| {
+        (0..set.len())
+            .max_by(|a, b| energy[set[*a]].total_cmp(&energy[set[*b]]))
+            .unwrap()
+    };
+    let largest_void = |points: &[bool], energy: &[f64]| {
+        let mut void = (f64::INFINITY, 0);
+        for (i, (set, energy)) in points.iter().zip(energy).enumerate() {
+            if !set && *energy < void.0 {
+                void = (*energy, i);
+            }
+        }
+        void.1
+    };
+
+    // Start from a tenth of the points set at random, then move points from the tightest
+    // cluster to the largest void until they are evenly spread.
+    let mut state = seed;
+    let mut points = vec![false; LEN];
+    let mut set = Vec::with_capacity(LEN / 10);
+    let mut energy = vec![0.0; LEN];
+    while set.len() < LEN / 10 {
+        let point = (split_mix(&mut state) % LEN as u64) as usize;
+        if !points[point] {
+            points[point] = true;
+            set.push(point);
+            update(&mut energy, point, 1.0);
+        }
+    }
+    for _ in 0..LEN {
+        let cluster = set.swap_remove(tightest_cluster(&set, &energy));
+        points[cluster] = false;
+        update(&mut energy, cluster, -1.0);
+
+        let void = largest_void(&points, &energy);
+        points[void] = true;
+        set.push(void);
+        update(&mut energy, void, 1.0);
+        if void == cluster {
+            break;
+        }
+    }
+
+    // Rank the initial points by removing the tightest clusters, then the rest by filling the
+    // largest voids.
+    let mut ranks = vec![0; LEN];
+    let (initial_points, initial_energy) = (points, energy.clone());
+    for rank in (0..set.len()).rev() {
+        let cluster = set.swap_remove(tightest_cluster(&set, &energy));
+        update(&mut energy, cluster, -1.0);
+        ranks[cluster] = rank;
+    }
+    let (mut points, mut energy) = (initial_points, initial_energy);
+    for rank in LEN / 10..LEN {
+        let void = largest_void(&points, &energy);
+        points[void] = true;
+        update(&mut energy, void, 1.0);
+        ranks[void] = rank;
+    }
+
+    ranks
+        .into_iter()
+        .map(|rank| (rank as f64 + 0.5) / LEN as f64)
+        .collect()
+}
+
+/// Returns the next value of the SplitMix64 generator.
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// The offsets to the right and below and the share of the error carried over to each.
+type Kernel = [(isize, usize, f64)];
+
+const FLOYD_STEINBERG: [(isize, usize, f64); 4] = [
+    (1, 0, 7.0 / 16.0),
+    (-1, 1, 3.0 / 16.0),
+    (0, 1, 5.0 / 16.0),
+    (1, 1, 1.0 / 16.0),
+];
+const ATKINSON: [(isize, usize, f64); 6] = [
+    (1, 0, 1.0 / 8.0),
+    (2, 0, 1.0 / 8.0),
+    (-1, 1, 1.0 / 8.0),
+    (0, 1, 1.0 / 8.0),
+    (1, 1, 1.0 / 8.0),
+    (0, 2, 1.0 / 8.0),
+];
+
+fn diffuse<P, Q>(
+    source: &[P],
+    destination: &mut [Q],
+    width: usize,
+    kernel: &Kernel,
+    mut quantize: impl FnMut(Point<P>) -> (Q, Point<P>),
+) where
+    P: Pixel,
+    f64: FromComponentCommon<P::Component>,
+{
+    let colors = source
+        .first()
+        .map_or(0, |pixel| pixel.color_array().as_slice().len());
+    let [min, max] =
+        [P::Component::COMPONENT_MIN, P::Component::COMPONENT_MAX].map(f64::from_component_common);
+    let row_len = width * colors;
+
+    // The errors carried over to the current row and the two rows below it.
+    let mut errors = vec![0.0; 3 * row_len];
+    for (source, destination) in source.chunks(width).zip(destination.chunks_mut(width)) {
+        for (x, (source, destination)) in source.iter().zip(destination).enumerate() {
+            let mut carried = errors[x * colors..].iter();
+            let point = normalize(*source)
+                .map_colors(|color| (color + carried.next().unwrap()).clamp(min, max));
+            let (pixel, quantized) = quantize(point);
+            *destination = pixel;
+
+            let colors_quantized = point.color_array().into_iter().zip(quantized.color_array());
+            for (i, (color, quantized)) in colors_quantized.enumerate() {
+                let error = color - quantized;
+                for &(dx, dy, share) in kernel {
+                    if let Some(x) = x.checked_add_signed(dx).filter(|x| *x < width) {
+                        errors[dy * row_len + x * colors + i] += error * share;
+                    }
+                }
+            }
+        }
+        errors.copy_within(row_len.., 0);
+        errors[2 * row_len..].fill(0.0);
+    }
+}
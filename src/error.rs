@@ -0,0 +1,327 @@
+use core::fmt;
+
+/// An error returned when two slices which must be the same length are not.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LengthMismatchError {
+    /// The length of the source slice.
+    pub source_len: usize,
+    /// The length of the destination slice.
+    pub destination_len: usize,
+}
+impl LengthMismatchError {
+    #[cfg_attr(not(feature = "libm"), allow(dead_code))]
+    pub(crate) fn check(source_len: usize, destination_len: usize) -> Result<(), Self> {
+        if source_len == destination_len {
+            Ok(())
+        } else {
+            Err(Self {
+                source_len,
+                destination_len,
+            })
+        }
+    }
+}
+impl fmt::Display for LengthMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "source slice has length {} but destination slice has length {}",
+            self.source_len, self.destination_len
+        )
+    }
+}
+impl core::error::Error for LengthMismatchError {}
+
+/// An error returned when a buffer cannot hold an image or frame of the given dimensions.
+///
+/// Lengths are measured in the elements of the buffer, such as bytes for frames.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LayoutError {
+    /// A row stride is smaller than the length of one row.
+    StrideTooSmall {
+        /// The length of one row.
+        required: usize,
+        /// The stride.
+        actual: usize,
+    },
+    /// A buffer is smaller than the image requires.
+    BufferTooSmall {
+        /// The length the buffer must have.
+        required: usize,
+        /// The length of the buffer.
+        actual: usize,
+    },
+    /// A buffer of bytes could not be cast to components.
+    Cast(CastError),
+}
+impl LayoutError {
+    /// Returns the length of `count` elements of `size`, or an error if it overflows as no buffer
+    /// of length `len` could hold that many.
+    pub(crate) fn checked_mul(size: usize, count: usize, len: usize) -> Result<usize, Self> {
+        size.checked_mul(count).ok_or(LayoutError::BufferTooSmall {
+            required: usize::MAX,
+            actual: len,
+        })
+    }
+    /// Checks that a buffer of length `len` holds `rows` rows of `row_len` elements, each `stride`
+    /// elements apart.
+    pub(crate) fn check(
+        len: usize,
+        stride: usize,
+        row_len: usize,
+        rows: usize,
+    ) -> Result<(), Self> {
+        if stride < row_len {
+            return Err(LayoutError::StrideTooSmall {
+                required: row_len,
+                actual: stride,
+            });
+        }
+
+        let required = match rows {
+            0 => Some(0),
+            rows => (rows - 1)
+                .checked_mul(stride)
+                .and_then(|n| n.checked_add(row_len)),
+        };
+        match required {
+            Some(required) if required <= len => Ok(()),
+            required => Err(LayoutError::BufferTooSmall {
+                required: required.unwrap_or(usize::MAX),
+                actual: len,
+            }),
+        }
+    }
+}
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::StrideTooSmall { required, actual } => write!(
+                f,
+                "stride of {actual} is smaller than the row length of {required}"
+            ),
+            LayoutError::BufferTooSmall { required, actual } => write!(
+                f,
+                "buffer of length {actual} is smaller than the required length of {required}"
+            ),
+            LayoutError::Cast(error) => write!(f, "{error}"),
+        }
+    }
+}
+impl core::error::Error for LayoutError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            LayoutError::Cast(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+impl From<CastError> for LayoutError {
+    fn from(error: CastError) -> Self {
+        LayoutError::Cast(error)
+    }
+}
+
+/// An error returned when a slice cannot be reinterpreted as a slice of another type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CastError {
+    /// The size of the source is not a multiple of the size of the destination type.
+    SizeMismatch,
+    /// The source is not aligned for the destination type.
+    AlignmentMismatch,
+}
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::SizeMismatch => write!(
+                f,
+                "source size is not a multiple of the destination type's size"
+            ),
+            CastError::AlignmentMismatch => {
+                write!(f, "source is not aligned for the destination type")
+            }
+        }
+    }
+}
+impl core::error::Error for CastError {}
+
+/// An error returned when a pixel is constructed from the wrong number of components.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let components: &[u8] = &[1, 2, 3];
+///
+/// assert_eq!(Rgb::<u8>::try_from(components), Ok(Rgb { r: 1, g: 2, b: 3 }));
+/// assert_eq!(
+///     Rgba::<u8>::try_from(components),
+///     Err(FromComponentsError::TooFew { expected: 4, actual: 3 })
+/// );
+/// assert_eq!(
+///     Gray::<u8>::try_from(components),
+///     Err(FromComponentsError::TooMany { expected: 1 })
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FromComponentsError {
+    /// Fewer components were given than the pixel needs.
+    TooFew {
+        /// The number of components the pixel needs.
+        expected: usize,
+        /// The number of components given.
+        actual: usize,
+    },
+    /// More components were given than the pixel needs.
+    TooMany {
+        /// The number of components the pixel needs.
+        expected: usize,
+    },
+}
+impl FromComponentsError {
+    /// Returns the error for `actual` components given to a pixel which needs a different number,
+    /// `expected`.
+    pub(crate) fn mismatch(expected: usize, actual: usize) -> Self {
+        if actual < expected {
+            FromComponentsError::TooFew { expected, actual }
+        } else {
+            FromComponentsError::TooMany { expected }
+        }
+    }
+}
+impl fmt::Display for FromComponentsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromComponentsError::TooFew { expected, actual } => write!(
+                f,
+                "expected {expected} components but only {actual} were given"
+            ),
+            FromComponentsError::TooMany { expected } => {
+                write!(f, "expected {expected} components but more were given")
+            }
+        }
+    }
+}
+impl core::error::Error for FromComponentsError {}
+
+/// An error returned when pixels cannot be quantized to a palette.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PaletteError {
+    /// The palette has no colors.
+    Empty,
+    /// The index type cannot hold the index of every color in the palette.
+    TooManyColors {
+        /// The number of colors in the palette.
+        len: usize,
+    },
+    /// See [`LengthMismatchError`].
+    LengthMismatch(LengthMismatchError),
+}
+impl fmt::Display for PaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaletteError::Empty => write!(f, "palette has no colors"),
+            PaletteError::TooManyColors { len } => write!(
+                f,
+                "palette has {len} colors which is more than the index type can hold"
+            ),
+            PaletteError::LengthMismatch(error) => write!(f, "{error}"),
+        }
+    }
+}
+impl core::error::Error for PaletteError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            PaletteError::LengthMismatch(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+impl From<LengthMismatchError> for PaletteError {
+    fn from(error: LengthMismatchError) -> Self {
+        PaletteError::LengthMismatch(error)
+    }
+}
+
+/// Any of the errors returned by this crate.
+///
+/// Every error type in this crate converts into this type, so it can be used with the `?`
+/// operator when a function returns several of them.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// fn decode(bytes: &[u8], gray: &mut [Gray<u8>]) -> Result<Rgb<u8>, Error> {
+///     let pixels = Rgb::slice_from_components(bytes)?;
+///     convert_slice(pixels, gray)?;
+///     Ok(Rgb::try_from(&bytes[..3])?)
+/// }
+///
+/// let mut gray = [Gray::default(); 2];
+/// assert_eq!(decode(&[1, 2, 3, 4, 5, 6], &mut gray), Ok(Rgb { r: 1, g: 2, b: 3 }));
+/// assert_eq!(
+///     decode(&[1, 2, 3, 4, 5, 6], &mut []),
+///     Err(Error::LengthMismatch(LengthMismatchError { source_len: 2, destination_len: 0 }))
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Error {
+    /// See [`LengthMismatchError`].
+    LengthMismatch(LengthMismatchError),
+    /// See [`LayoutError`].
+    Layout(LayoutError),
+    /// See [`CastError`].
+    Cast(CastError),
+    /// See [`FromComponentsError`].
+    FromComponents(FromComponentsError),
+    /// See [`PaletteError`].
+    Palette(PaletteError),
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::LengthMismatch(error) => write!(f, "{error}"),
+            Error::Layout(error) => write!(f, "{error}"),
+            Error::Cast(error) => write!(f, "{error}"),
+            Error::FromComponents(error) => write!(f, "{error}"),
+            Error::Palette(error) => write!(f, "{error}"),
+        }
+    }
+}
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::LengthMismatch(error) => Some(error),
+            Error::Layout(error) => Some(error),
+            Error::Cast(error) => Some(error),
+            Error::FromComponents(error) => Some(error),
+            Error::Palette(error) => Some(error),
+        }
+    }
+}
+impl From<LengthMismatchError> for Error {
+    fn from(error: LengthMismatchError) -> Self {
+        Error::LengthMismatch(error)
+    }
+}
+impl From<LayoutError> for Error {
+    fn from(error: LayoutError) -> Self {
+        Error::Layout(error)
+    }
+}
+impl From<CastError> for Error {
+    fn from(error: CastError) -> Self {
+        Error::Cast(error)
+    }
+}
+impl From<FromComponentsError> for Error {
+    fn from(error: FromComponentsError) -> Self {
+        Error::FromComponents(error)
+    }
+}
+impl From<PaletteError> for Error {
+    fn from(error: PaletteError) -> Self {
+        Error::Palette(error)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::*;
+
+pub mod packed;
+pub mod planar;
+pub mod semi_planar;
+
+/// A view of a frame of [`YCbCr`] pixels stored in a planar or chroma-subsampled layout.
+///
+/// Chroma is upsampled by taking the nearest chroma sample.
+pub trait YCbCrFrame {
+    /// The component type of the frame's pixels.
+    type Component: PixelComponent;
+
+    /// Returns the width of the frame in pixels.
+    fn width(&self) -> usize;
+    /// Returns the height of the frame in pixels.
+    fn height(&self) -> usize;
+
+    /// Returns the pixel at column `x` and row `y`, or `None` if it is outside the frame.
+    fn get(&self, x: usize, y: usize) -> Option<YCbCr<Self::Component>>;
+
+    /// Returns an iterator over the pixels of the frame, row by row.
+    fn pixels(&self) -> impl Iterator<Item = YCbCr<Self::Component>> + '_
+    where
+        Self: Sized,
+    {
+        (0..self.height())
+            .flat_map(move |y| (0..self.width()).map(move |x| self.get(x, y).unwrap()))
+    }
+
+    /// Converts each pixel of the frame to an RGB family pixel in `destination`, row by row,
+    /// using [`YCbCr::to_rgb()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatchError`] and leaves `destination` untouched if its length is not
+    /// the number of pixels in the frame.
+    #[cfg(feature = "libm")]
+    fn convert<P>(
+        &self,
+        destination: &mut [P],
+        matrix: YCbCrMatrix,
+        range: YCbCrRange,
+    ) -> Result<(), LengthMismatchError>
+    where
+        P: FromPixelCommon<Rgba<f64>>,
+        Self: Sized,
+    {
+        LengthMismatchError::check(self.width() * self.height(), destination.len())?;
+        for (pixel, destination) in self.pixels().zip(destination) {
+            *destination = pixel.to_rgb(matrix, range);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of chroma samples for a dimension subsampled by two.
+fn subsampled(length: usize) -> usize {
+    length.div_ceil(2)
+}
+
+/// A plane of samples within a byte buffer.
+#[derive(Copy, Clone, Debug)]
+struct Plane<'a> {
+    data: &'a [u8],
+    stride: usize,
+}
+impl<'a> Plane<'a> {
+    /// Checks that `data` holds `rows` rows of `row_len` bytes each, `stride` bytes apart.
+    fn new(
+        data: &'a [u8],
+        stride: usize,
+        row_len: usize,
+        rows: usize,
+    ) -> Result<Self, LayoutError> {
+        LayoutError::check(data.len(), stride, row_len, rows)?;
+        Ok(Self { data, stride })
+    }
+
+    /// Splits a tightly packed plane of `rows` rows of `row_len` bytes off the front of `data`.
+    fn split(data: &'a [u8], row_len: usize, rows: usize) -> Result<(Self, &'a [u8]), LayoutError> {
+        let plane = Self::new(data, row_len, row_len, rows)?;
+        Ok((plane, &data[row_len * rows..]))
+    }
+
+    fn u8(&self, x: usize, y: usize) -> u8 {
+        self.data[y * self.stride + x]
+    }
+    /// Reads a little-endian 16-bit sample holding a 10-bit value in its most significant bits.
+    fn u10(&self, x: usize, y: usize) -> Unorm<u16, 10> {
+        let i = y * self.stride + 2 * x;
+        Unorm(u16::from_le_bytes([self.data[i], self.data[i + 1]]) >> 6)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::*;
+
+use super::{subsampled, Plane};
+
+macro_rules! packed {
+    ($(#[$meta:meta])* $name:ident, $y0:literal, $cb:literal, $y1:literal, $cr:literal) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name<'a> {
+            plane: Plane<'a>,
+            width: usize,
+            height: usize,
+        }
+        impl<'a> $name<'a> {
+            /// Creates a view of a frame whose rows are tightly packed in `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`LayoutError`] if `data` is too small for the frame.
+            pub fn new(data: &'a [u8], width: usize, height: usize) -> Result<Self, LayoutError> {
+                let row_len = LayoutError::checked_mul(4, subsampled(width), data.len())?;
+                Self::with_stride(data, row_len, width, height)
+            }
+
+            /// Creates a view of a frame whose rows are `stride` bytes apart in `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`LayoutError`] if `stride` is smaller than a row or `data` is too
+            /// small for the frame.
+            pub fn with_stride(
+                data: &'a [u8],
+                stride: usize,
+                width: usize,
+                height: usize,
+            ) -> Result<Self, LayoutError> {
+                let row_len = LayoutError::checked_mul(4, subsampled(width), data.len())?;
+                Ok(Self {
+                    plane: Plane::new(data, stride, row_len, height)?,
+                    width,
+                    height,
+                })
+            }
+        }
+        impl YCbCrFrame for $name<'_> {
+            type Component = u8;
+
+            fn width(&self) -> usize {
+                self.width
+            }
+            fn height(&self) -> usize {
+                self.height
+            }
+
+            fn get(&self, x: usize, y: usize) -> Option<YCbCr<u8>> {
+                if x >= self.width || y >= self.height {
+                    return None;
+                }
+
+                let pair = x / 2 * 4;
+                let luma = if x % 2 == 0 { $y0 } else { $y1 };
+                Some(YCbCr {
+                    y: self.plane.u8(pair + luma, y),
+                    cb: self.plane.u8(pair + $cb, y),
+                    cr: self.plane.u8(pair + $cr, y),
+                })
+            }
+        }
+    };
+}
+
+packed!(
+    /// A view of a `YUY2` frame, with each pair of pixels packed as `Y0 U Y1 V` so chroma is
+    /// subsampled by two horizontally.
+    Yuy2,
+    0,
+    1,
+    2,
+    3
+);
+packed!(
+    /// A view of a `UYVY` frame, with each pair of pixels packed as `U Y0 V Y1` so chroma is
+    /// subsampled by two horizontally.
+    Uyvy,
+    1,
+    0,
+    3,
+    2
+);
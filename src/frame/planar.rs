@@ -0,0 +1,99 @@
+use crate::*;
+
+use super::{subsampled, Plane};
+
+/// A view of an `I420` frame, with a full resolution Y plane followed by U and V planes
+/// subsampled by two in both dimensions.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// // A 2x2 white frame in limited range.
+/// let data = [235, 235, 235, 235, 128, 128];
+/// let frame = I420::new(&data, 2, 2).unwrap();
+///
+/// let mut rgb = [Rgb::<u8>::default(); 4];
+/// frame.convert(&mut rgb, YCbCrMatrix::Bt601, YCbCrRange::Limited).unwrap();
+///
+/// assert_eq!(rgb, [Rgb { r: 255, g: 255, b: 255 }; 4]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct I420<'a> {
+    y: Plane<'a>,
+    u: Plane<'a>,
+    v: Plane<'a>,
+    width: usize,
+    height: usize,
+}
+impl<'a> I420<'a> {
+    /// Creates a view of a frame whose planes are tightly packed one after another in `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LayoutError`] if `data` is too small for the frame.
+    pub fn new(data: &'a [u8], width: usize, height: usize) -> Result<Self, LayoutError> {
+        let (chroma_width, chroma_height) = (subsampled(width), subsampled(height));
+        let (y, data) = Plane::split(data, width, height)?;
+        let (u, data) = Plane::split(data, chroma_width, chroma_height)?;
+        let (v, _) = Plane::split(data, chroma_width, chroma_height)?;
+
+        Ok(Self {
+            y,
+            u,
+            v,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a view of a frame from separate planes, each with its own row stride in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LayoutError`] if a stride is smaller than its plane's rows or a plane is too
+    /// small for the frame.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_planes(
+        y: &'a [u8],
+        y_stride: usize,
+        u: &'a [u8],
+        u_stride: usize,
+        v: &'a [u8],
+        v_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<Self, LayoutError> {
+        let (chroma_width, chroma_height) = (subsampled(width), subsampled(height));
+
+        Ok(Self {
+            y: Plane::new(y, y_stride, width, height)?,
+            u: Plane::new(u, u_stride, chroma_width, chroma_height)?,
+            v: Plane::new(v, v_stride, chroma_width, chroma_height)?,
+            width,
+            height,
+        })
+    }
+}
+impl YCbCrFrame for I420<'_> {
+    type Component = u8;
+
+    fn width(&self) -> usize {
+        self.width
+    }
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<YCbCr<u8>> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        Some(YCbCr {
+            y: self.y.u8(x, y),
+            cb: self.u.u8(x / 2, y / 2),
+            cr: self.v.u8(x / 2, y / 2),
+        })
+    }
+}
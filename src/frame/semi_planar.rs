@@ -0,0 +1,150 @@
+use crate::*;
+
+use super::{subsampled, Plane};
+
+macro_rules! semi_planar {
+    ($(#[$meta:meta])* $name:ident, $component:ty, $sample:ident, $size:literal, $cb:literal, $cr:literal) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name<'a> {
+            y: Plane<'a>,
+            chroma: Plane<'a>,
+            width: usize,
+            height: usize,
+        }
+        impl<'a> $name<'a> {
+            /// Creates a view of a frame whose planes are tightly packed one after another in
+            /// `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`LayoutError`] if `data` is too small for the frame.
+            pub fn new(data: &'a [u8], width: usize, height: usize) -> Result<Self, LayoutError> {
+                let y_len = LayoutError::checked_mul($size, width, data.len())?;
+                let chroma_len = LayoutError::checked_mul(2 * $size, subsampled(width), data.len())?;
+
+                let (y, data) = Plane::split(data, y_len, height)?;
+                let (chroma, _) = Plane::split(data, chroma_len, subsampled(height))?;
+
+                Ok(Self {
+                    y,
+                    chroma,
+                    width,
+                    height,
+                })
+            }
+
+            /// Creates a view of a frame from separate planes, each with its own row stride in
+            /// bytes.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`LayoutError`] if a stride is smaller than its plane's rows or a plane
+            /// is too small for the frame.
+            pub fn from_planes(
+                y: &'a [u8],
+                y_stride: usize,
+                chroma: &'a [u8],
+                chroma_stride: usize,
+                width: usize,
+                height: usize,
+            ) -> Result<Self, LayoutError> {
+                let y_len = LayoutError::checked_mul($size, width, y.len())?;
+                let chroma_len =
+                    LayoutError::checked_mul(2 * $size, subsampled(width), chroma.len())?;
+
+                Ok(Self {
+                    y: Plane::new(y, y_stride, y_len, height)?,
+                    chroma: Plane::new(chroma, chroma_stride, chroma_len, subsampled(height))?,
+                    width,
+                    height,
+                })
+            }
+        }
+        impl YCbCrFrame for $name<'_> {
+            type Component = $component;
+
+            fn width(&self) -> usize {
+                self.width
+            }
+            fn height(&self) -> usize {
+                self.height
+            }
+
+            fn get(&self, x: usize, y: usize) -> Option<YCbCr<$component>> {
+                if x >= self.width || y >= self.height {
+                    return None;
+                }
+
+                let chroma_x = x / 2 * 2;
+                Some(YCbCr {
+                    y: self.y.$sample(x, y),
+                    cb: self.chroma.$sample(chroma_x + $cb, y / 2),
+                    cr: self.chroma.$sample(chroma_x + $cr, y / 2),
+                })
+            }
+        }
+    };
+}
+
+semi_planar!(
+    /// A view of an `NV12` frame, with a full resolution Y plane followed by an interleaved UV
+    /// plane subsampled by two in both dimensions.
+    Nv12,
+    u8,
+    u8,
+    1,
+    0,
+    1
+);
+semi_planar!(
+    /// A view of an `NV21` frame, with a full resolution Y plane followed by an interleaved VU
+    /// plane subsampled by two in both dimensions.
+    Nv21,
+    u8,
+    u8,
+    1,
+    1,
+    0
+);
+semi_planar!(
+    /// A view of a `P010` frame, laid out like [`Nv12`] but with little-endian 16-bit samples
+    /// holding 10-bit values in their most significant bits.
+    ///
+    /// Samples are returned as 10-bit [`Unorm`] components, so the full scale code of `65472`
+    /// converts to `1.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// // A 2x2 frame of limited range white.
+    /// let mut data = [0; 12];
+    /// for y in data[..8].chunks_exact_mut(2) {
+    ///     y.copy_from_slice(&(940_u16 << 6).to_le_bytes());
+    /// }
+    /// for chroma in data[8..].chunks_exact_mut(2) {
+    ///     chroma.copy_from_slice(&(512_u16 << 6).to_le_bytes());
+    /// }
+    ///
+    /// let frame = P010::new(&data, 2, 2).unwrap();
+    /// assert_eq!(frame.get(1, 1), Some(YCbCr { y: Unorm(940), cb: Unorm(512), cr: Unorm(512) }));
+    ///
+    /// let mut rgb = [Rgb::<u8>::default(); 4];
+    /// frame.convert(&mut rgb, YCbCrMatrix::Bt2020, YCbCrRange::Limited).unwrap();
+    /// assert_eq!(rgb, [Rgb { r: 255, g: 255, b: 255 }; 4]);
+    ///
+    /// let full_scale = 65472_u16.to_le_bytes();
+    /// let data = [full_scale, full_scale, full_scale].concat();
+    /// let frame = P010::new(&data, 1, 1).unwrap();
+    /// assert_eq!(f32::from_component_common(frame.get(0, 0).unwrap().y), 1.0);
+    ///
+    /// assert!(P010::new(&data, usize::MAX, 1).is_err());
+    /// ```
+    P010,
+    Unorm<u16, 10>,
+    u10,
+    2,
+    0,
+    1
+);
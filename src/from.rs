@@ -39,6 +39,15 @@ between!(Argb, Abgr, {r, g, b, a});
 between!(Bgra, Abgr, {r, g, b, a});
 
 between!(GrayAlpha, Gray, { gray });
+between!(Hsla, Hsl, {h, s, l});
+between!(Hsva, Hsv, {h, s, v});
+between!(Hwba, Hwb, {h, w, b});
+between!(Xyza, Xyz, {x, y, z});
+between!(Laba, Lab, {l, a, b});
+between!(Lcha, Lch, {l, c, h});
+between!(Oklaba, Oklab, {l, a, b});
+between!(Oklcha, Oklch, {l, c, h});
+between!(YCbCrA, YCbCr, {y, cb, cr});
 
 macro_rules! with_array {
     ($type:ident, $length:literal, [$($bit:ident),*]) => {
@@ -49,8 +58,18 @@ macro_rules! with_array {
         }
         impl<R, S> From<[R; $length]> for $type<S> where R: Into<S> {
             fn from(value: [R; $length]) -> Self {
-                let mut iter = value.into_iter();
-                Self{$($bit: iter.next().unwrap().into()),*}
+                let [$($bit),*] = value;
+                Self{$($bit: $bit.into()),*}
+            }
+        }
+        impl<R, S> TryFrom<&[R]> for $type<S> where R: Copy + Into<S> {
+            type Error = FromComponentsError;
+
+            fn try_from(value: &[R]) -> Result<Self, Self::Error> {
+                match *value {
+                    [$($bit),*] => Ok(Self{$($bit: $bit.into()),*}),
+                    _ => Err(FromComponentsError::mismatch($length, value.len())),
+                }
             }
         }
     };
@@ -64,3 +83,40 @@ with_array!(Bgra, 4, [b, g, r, a]);
 with_array!(Abgr, 4, [a, b, g, r]);
 with_array!(Gray, 1, [gray]);
 with_array!(GrayAlpha, 2, [gray, a]);
+with_array!(Hsl, 3, [h, s, l]);
+with_array!(Hsla, 4, [h, s, l, a]);
+with_array!(Hsv, 3, [h, s, v]);
+with_array!(Hsva, 4, [h, s, v, a]);
+with_array!(Hwb, 3, [h, w, b]);
+with_array!(Hwba, 4, [h, w, b, a]);
+with_array!(Xyz, 3, [x, y, z]);
+with_array!(Xyza, 4, [x, y, z, a]);
+with_array!(Lab, 3, [l, a, b]);
+with_array!(Laba, 4, [l, a, b, alpha]);
+with_array!(Lch, 3, [l, c, h]);
+with_array!(Lcha, 4, [l, c, h, a]);
+with_array!(Oklab, 3, [l, a, b]);
+with_array!(Oklaba, 4, [l, a, b, alpha]);
+with_array!(Oklch, 3, [l, c, h]);
+with_array!(Oklcha, 4, [l, c, h, a]);
+with_array!(YCbCr, 3, [y, cb, cr]);
+with_array!(YCbCrA, 4, [y, cb, cr, a]);
+with_array!(Indexed, 1, [index]);
+
+macro_rules! wrapper {
+    ($wrapper:ident) => {
+        impl<'a, T, P> TryFrom<&'a [T]> for $wrapper<P>
+        where
+            P: TryFrom<&'a [T]>,
+        {
+            type Error = P::Error;
+
+            fn try_from(value: &'a [T]) -> Result<Self, Self::Error> {
+                P::try_from(value).map($wrapper)
+            }
+        }
+    };
+}
+
+wrapper!(Premultiplied);
+wrapper!(Linear);
@@ -0,0 +1,132 @@
+// Chromatic adaptation between white points, which models how colors appear under a different
+// light source and is used to white balance images.
+
+use super::perceptual::{adaptation, multiply, Matrix, BRADFORD};
+use crate::*;
+
+/// A chromatic adaptation transform, which converts colors seen under one white point into the
+/// colors that look the same under another by scaling cone responses.
+///
+/// Pixels are converted via [`Xyza`] using [`FromPixelCommon`], so any pixel of the RGB family can
+/// be adapted as well as [`Xyz`] and [`Xyza`] pixels. RGB pixels are assumed to be sRGB encoded.
+///
+/// # Examples
+/// White balancing a photo taken under tungsten light, whose white appears orange, so that its
+/// white appears neutral under the [`WhitePoint::D65`] white point of sRGB.
+/// ```
+/// use pixeli::*;
+///
+/// let tungsten = WhitePoint::from_temperature(2856.0);
+/// let gray = tungsten.xyz::<f64>().map_colors(|x| x * 0.5);
+///
+/// let mut photo = [Rgb::<u16>::from_pixel_common(gray); 4];
+/// assert!(photo[0].r > photo[0].b);
+///
+/// ChromaticAdaptation::Bradford.adapt_slice(&mut photo, tungsten, WhitePoint::D65);
+/// for pixel in photo {
+///     assert!(pixel.r.abs_diff(pixel.g) <= 1 && pixel.g.abs_diff(pixel.b) <= 1);
+/// }
+///
+/// let mut photo = [Rgb::<f32>::from_pixel_common(gray); 4];
+///
+/// ChromaticAdaptation::Cat02.adapt_slice(&mut photo, tungsten, WhitePoint::D65);
+/// for pixel in photo {
+///     assert!((pixel.r - pixel.g).abs() < 1e-5 && (pixel.g - pixel.b).abs() < 1e-5);
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform, used by ICC profiles.
+    #[default]
+    Bradford,
+    /// The von Kries transform using the Hunt-Pointer-Estevez cone responses.
+    VonKries,
+    /// The CAT02 transform from the CIECAM02 color appearance model.
+    Cat02,
+}
+
+impl ChromaticAdaptation {
+    /// Adapts `pixel` from the `source` white point to the `destination` white point.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let xyz = Xyz::<f64> { x: 0.5, y: 0.4, z: 0.3 };
+    /// let adapted = ChromaticAdaptation::Bradford.adapt(xyz, WhitePoint::D65, WhitePoint::D50);
+    ///
+    /// // Within rounding of the published Bradford matrix from D65 to D50, which uses slightly
+    /// // different white points.
+    /// let expected = Xyz {
+    ///     x: 1.0478112 * 0.5 + 0.0228866 * 0.4 - 0.0501270 * 0.3,
+    ///     y: 0.0295424 * 0.5 + 0.9904844 * 0.4 - 0.0170491 * 0.3,
+    ///     z: -0.0092345 * 0.5 + 0.0150436 * 0.4 + 0.7521316 * 0.3,
+    /// };
+    /// assert!((adapted.x - expected.x).abs() < 5e-4);
+    /// assert!((adapted.y - expected.y).abs() < 5e-4);
+    /// assert!((adapted.z - expected.z).abs() < 5e-4);
+    ///
+    /// // The source white always becomes the destination white.
+    /// for adaptation in [
+    ///     ChromaticAdaptation::Bradford,
+    ///     ChromaticAdaptation::VonKries,
+    ///     ChromaticAdaptation::Cat02,
+    /// ] {
+    ///     let white = adaptation.adapt(WhitePoint::D65.xyz::<f64>(), WhitePoint::D65, WhitePoint::D50);
+    ///     let expected = WhitePoint::D50.xyz::<f64>();
+    ///     assert!((white.x - expected.x).abs() < 1e-12 && (white.z - expected.z).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn adapt<P>(self, pixel: P, source: WhitePoint, destination: WhitePoint) -> P
+    where
+        P: Pixel + FromPixelCommon<Xyza<f64>>,
+        Xyza<f64>: FromPixelCommon<P>,
+    {
+        adapt(&self.matrix(source, destination), pixel)
+    }
+    /// Adapts each pixel in `pixels` from the `source` white point to the `destination` white
+    /// point in place, as in [`ChromaticAdaptation::adapt()`].
+    pub fn adapt_slice<P>(self, pixels: &mut [P], source: WhitePoint, destination: WhitePoint)
+    where
+        P: Pixel + FromPixelCommon<Xyza<f64>>,
+        Xyza<f64>: FromPixelCommon<P>,
+    {
+        let matrix = self.matrix(source, destination);
+        for pixel in pixels {
+            *pixel = adapt(&matrix, *pixel);
+        }
+    }
+
+    /// Returns the matrix adapting XYZ colors from `source` to `destination`.
+    fn matrix(self, source: WhitePoint, destination: WhitePoint) -> Matrix {
+        let to_cone = match self {
+            ChromaticAdaptation::Bradford => &BRADFORD,
+            ChromaticAdaptation::VonKries => &VON_KRIES,
+            ChromaticAdaptation::Cat02 => &CAT02,
+        };
+        adaptation(to_cone, source, destination)
+    }
+}
+
+/// XYZ to cone responses for the transforms other than Bradford.
+const VON_KRIES: Matrix = [
+    [0.40024, 0.70760, -0.08081],
+    [-0.22630, 1.16532, 0.04570],
+    [0.0, 0.0, 0.91822],
+];
+const CAT02: Matrix = [
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+];
+
+fn adapt<P>(matrix: &Matrix, pixel: P) -> P
+where
+    P: Pixel + FromPixelCommon<Xyza<f64>>,
+    Xyza<f64>: FromPixelCommon<P>,
+{
+    let xyza = Xyza::from_pixel_common(pixel);
+    let [x, y, z] = multiply(matrix, [xyza.x, xyza.y, xyza.z]);
+    P::from_pixel_common(Xyza { x, y, z, a: xyza.a })
+}
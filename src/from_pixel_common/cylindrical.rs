@@ -0,0 +1,139 @@
+// Conversions between the RGB family of pixels and the cylindrical `Hsl`, `Hsv` and `Hwb`
+// families of pixels.
+//
+// All conversions go via `Rgba<f64>` and the alpha variant of the cylindrical pixel with `f64`
+// components, so every component type is supported using `FromComponentCommon`. Hue is a
+// fraction of a full turn, wrapping around at the maximum component value, and is zero for
+// achromatic colors.
+
+use crate::*;
+
+/// Wraps `hue` into the `0.0..1.0` range.
+fn wrap(hue: f64) -> f64 {
+    hue - libm::floor(hue)
+}
+
+/// Returns the hue, minimum and maximum of an RGB color.
+fn hue_min_max([r, g, b]: [f64; 3]) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        (g - b) / chroma
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+
+    (wrap(hue / 6.0), min, max)
+}
+
+fn rgb_to_hsl(rgb: [f64; 3]) -> [f64; 3] {
+    let (h, min, max) = hue_min_max(rgb);
+    let l = (max + min) / 2.0;
+    let s = if l <= 0.0 || l >= 1.0 {
+        0.0
+    } else {
+        (max - l) / l.min(1.0 - l)
+    };
+
+    [h, s, l]
+}
+fn hsl_to_rgb([h, s, l]: [f64; 3]) -> [f64; 3] {
+    let a = s * l.min(1.0 - l);
+    let f = |n: f64| {
+        let k = (n + wrap(h) * 12.0) % 12.0;
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+fn rgb_to_hsv(rgb: [f64; 3]) -> [f64; 3] {
+    let (h, min, max) = hue_min_max(rgb);
+    let s = if max <= 0.0 { 0.0 } else { (max - min) / max };
+
+    [h, s, max]
+}
+fn hsv_to_rgb([h, s, v]: [f64; 3]) -> [f64; 3] {
+    let f = |n: f64| {
+        let k = (n + wrap(h) * 6.0) % 6.0;
+        v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+
+    [f(5.0), f(3.0), f(1.0)]
+}
+
+fn rgb_to_hwb(rgb: [f64; 3]) -> [f64; 3] {
+    let (h, min, max) = hue_min_max(rgb);
+
+    [h, min, 1.0 - max]
+}
+fn hwb_to_rgb([h, w, b]: [f64; 3]) -> [f64; 3] {
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return [gray; 3];
+    }
+
+    hsv_to_rgb([h, 1.0, 1.0]).map(|c| c * (1.0 - w - b) + w)
+}
+
+macro_rules! rgb_cylindrical {
+    ($rgb:ident, $cylindrical:ident, $cylindrical_alpha:ident, $from_rgb:ident, $to_rgb:ident) => {
+        rgb_cylindrical!(@one $rgb, $cylindrical, $cylindrical_alpha, $from_rgb, $to_rgb);
+        rgb_cylindrical!(@one $rgb, $cylindrical_alpha, $cylindrical_alpha, $from_rgb, $to_rgb);
+    };
+    (@one $rgb:ident, $cylindrical:ident, $cylindrical_alpha:ident, $from_rgb:ident, $to_rgb:ident) => {
+        impl<R, S> FromPixelCommon<$rgb<R>> for $cylindrical<S>
+        where
+            R: PixelComponent + Enlargeable,
+            S: PixelComponent + FromComponentCommon<f64>,
+            f64: FromComponentCommon<R>,
+        {
+            fn from_pixel_common(pixel: $rgb<R>) -> Self {
+                let rgba = Rgba::<f64>::from_pixel_common(pixel);
+                let colors = $from_rgb(rgba.color_array());
+
+                Self::from_pixel_common($cylindrical_alpha::from_colors_alpha(colors, rgba.a))
+            }
+        }
+        impl<R, S> FromPixelCommon<$cylindrical<R>> for $rgb<S>
+        where
+            R: PixelComponent + Enlargeable,
+            S: PixelComponent + FromComponentCommon<f64>,
+            f64: FromComponentCommon<R>,
+        {
+            fn from_pixel_common(pixel: $cylindrical<R>) -> Self {
+                let cylindrical = $cylindrical_alpha::<f64>::from_pixel_common(pixel);
+                let colors = $to_rgb(cylindrical.color_array());
+
+                Self::from_pixel_common(Rgba::from_colors_alpha(colors, cylindrical.a))
+            }
+        }
+    };
+}
+
+rgb_cylindrical!(Rgb, Hsl, Hsla, rgb_to_hsl, hsl_to_rgb);
+rgb_cylindrical!(Bgr, Hsl, Hsla, rgb_to_hsl, hsl_to_rgb);
+rgb_cylindrical!(Rgba, Hsl, Hsla, rgb_to_hsl, hsl_to_rgb);
+rgb_cylindrical!(Argb, Hsl, Hsla, rgb_to_hsl, hsl_to_rgb);
+rgb_cylindrical!(Bgra, Hsl, Hsla, rgb_to_hsl, hsl_to_rgb);
+rgb_cylindrical!(Abgr, Hsl, Hsla, rgb_to_hsl, hsl_to_rgb);
+
+rgb_cylindrical!(Rgb, Hsv, Hsva, rgb_to_hsv, hsv_to_rgb);
+rgb_cylindrical!(Bgr, Hsv, Hsva, rgb_to_hsv, hsv_to_rgb);
+rgb_cylindrical!(Rgba, Hsv, Hsva, rgb_to_hsv, hsv_to_rgb);
+rgb_cylindrical!(Argb, Hsv, Hsva, rgb_to_hsv, hsv_to_rgb);
+rgb_cylindrical!(Bgra, Hsv, Hsva, rgb_to_hsv, hsv_to_rgb);
+rgb_cylindrical!(Abgr, Hsv, Hsva, rgb_to_hsv, hsv_to_rgb);
+
+rgb_cylindrical!(Rgb, Hwb, Hwba, rgb_to_hwb, hwb_to_rgb);
+rgb_cylindrical!(Bgr, Hwb, Hwba, rgb_to_hwb, hwb_to_rgb);
+rgb_cylindrical!(Rgba, Hwb, Hwba, rgb_to_hwb, hwb_to_rgb);
+rgb_cylindrical!(Argb, Hwb, Hwba, rgb_to_hwb, hwb_to_rgb);
+rgb_cylindrical!(Bgra, Hwb, Hwba, rgb_to_hwb, hwb_to_rgb);
+rgb_cylindrical!(Abgr, Hwb, Hwba, rgb_to_hwb, hwb_to_rgb);
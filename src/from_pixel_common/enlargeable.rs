@@ -1,4 +1,4 @@
-use num_traits::NumCast;
+use num_traits::{NumCast, One, ToPrimitive, Zero};
 
 use crate::*;
 
@@ -51,3 +51,121 @@ implement_enlargeable!(isize, i128);
 
 implement_enlargeable!(f32, f64);
 implement_enlargeable!(f64, f64);
+
+macro_rules! implement_enlargeable_normalized {
+    ($normalized:ty, $int:ident, [$($generics:tt)*]) => {
+        impl<$($generics)*> Enlargeable for $normalized {
+            type Larger = <$int as Enlargeable>::Larger;
+        }
+    };
+}
+implement_enlargeable_normalized!(Unorm<u8, BITS>, u8, [const BITS: u32]);
+implement_enlargeable_normalized!(Unorm<u16, BITS>, u16, [const BITS: u32]);
+implement_enlargeable_normalized!(Unorm<u32, BITS>, u32, [const BITS: u32]);
+implement_enlargeable_normalized!(Unorm<u64, BITS>, u64, [const BITS: u32]);
+implement_enlargeable_normalized!(Snorm<i8>, i8, []);
+implement_enlargeable_normalized!(Snorm<i16>, i16, []);
+implement_enlargeable_normalized!(Snorm<i32>, i32, []);
+implement_enlargeable_normalized!(Snorm<i64>, i64, []);
+
+#[cfg(feature = "half")]
+impl Enlargeable for half::f16 {
+    type Larger = f32;
+}
+#[cfg(feature = "half")]
+impl Enlargeable for half::bf16 {
+    type Larger = f32;
+}
+
+fn is_integer<L>() -> bool
+where
+    L: PixelComponent,
+{
+    // Only integer division truncates `1 / 2` to zero.
+    L::one() / (L::one() + L::one()) == L::zero()
+}
+
+/// Returns the offset of `x` from `T::COMPONENT_MIN`, which is never negative.
+pub(crate) fn offset<T>(x: T) -> T::Larger
+where
+    T: Enlargeable,
+{
+    x.to_larger() - T::COMPONENT_MIN.to_larger()
+}
+/// The inverse of [`offset()`], clamping the result into the component range.
+pub(crate) fn from_offset<T>(n: T::Larger) -> T
+where
+    T: Enlargeable,
+{
+    T::clamp_from(n + T::COMPONENT_MIN.to_larger())
+}
+/// Returns the offset of `T::COMPONENT_MAX`.
+pub(crate) fn range<T>() -> T::Larger
+where
+    T: Enlargeable,
+{
+    offset(T::COMPONENT_MAX)
+}
+
+/// Calculates `(a * b + c * d) / divisor`, rounding to the nearest value for integers.
+///
+/// The inputs must be offsets such that `a * b + c * d <= range * range`. When that does not fit
+/// in `Enlargeable::Larger`, such as for `i16` whose range squared overflows `i32`, the
+/// calculation falls back to `f64`.
+pub(crate) fn sum_of_products_divide<T>(
+    a: T::Larger,
+    b: T::Larger,
+    c: T::Larger,
+    d: T::Larger,
+    divisor: T::Larger,
+) -> T::Larger
+where
+    T: Enlargeable,
+{
+    if !is_integer::<T::Larger>() {
+        return (a * b + c * d) / divisor;
+    }
+
+    let range = range::<T>();
+    if range <= T::Larger::COMPONENT_MAX / range {
+        let two = T::Larger::one() + T::Larger::one();
+        (a * b + c * d + divisor / two) / divisor
+    } else {
+        let [a, b, c, d, divisor] = [a, b, c, d, divisor].map(|x| x.to_f64().unwrap());
+        NumCast::from(libm::round((a * b + c * d) / divisor)).unwrap()
+    }
+}
+
+/// Multiplies two components as if they were both in the `0.0..=1.0` range.
+pub(crate) fn multiply<T>(x: T, y: T) -> T
+where
+    T: Enlargeable,
+{
+    let zero = T::Larger::zero();
+    from_offset(sum_of_products_divide::<T>(
+        offset(x),
+        offset(y),
+        zero,
+        zero,
+        range::<T>(),
+    ))
+}
+
+/// Divides `x` by `y` as if they were both in the `0.0..=1.0` range.
+///
+/// `y` must not be the minimum component value. `x` is clamped to `y` first so the result never
+/// exceeds the maximum component value.
+pub(crate) fn divide<T>(x: T, y: T) -> T
+where
+    T: Enlargeable,
+{
+    let zero = T::Larger::zero();
+    let x = if x > y { y } else { x };
+    from_offset(sum_of_products_divide::<T>(
+        offset(x),
+        range::<T>(),
+        zero,
+        zero,
+        offset(y),
+    ))
+}
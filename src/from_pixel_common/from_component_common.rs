@@ -1,31 +1,156 @@
+use crate::LengthMismatchError;
+
+use super::simd;
+
+#[cfg(feature = "half")]
+mod half;
+mod normalized;
+
 /// Convert between pixel component types using common component ranges.
 pub trait FromComponentCommon<T> {
     /// Converts to this type from the input component type.
     fn from_component_common(component: T) -> Self;
+
+    /// Converts each component in `source` into the same position in `destination`.
+    ///
+    /// The result is identical to calling [`FromComponentCommon::from_component_common()`] on
+    /// each component, but some common component pairs use faster implementations. Conversions
+    /// between `u8` and `f32` are vectorized on `x86_64` and `aarch64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices are
+    /// not the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let bytes: Vec<u8> = (0..=255).chain(0..100).collect();
+    /// let mut floats = vec![0.0; bytes.len()];
+    /// f32::from_component_common_slice(&bytes, &mut floats).unwrap();
+    ///
+    /// let mut round_trip = vec![0; floats.len()];
+    /// u8::from_component_common_slice(&floats, &mut round_trip).unwrap();
+    /// assert_eq!(round_trip, bytes);
+    ///
+    /// let floats = [-1.0, 0.1, 0.5, 0.502, 0.998, 1.5, f32::NAN, f32::INFINITY].repeat(5);
+    /// let mut bytes = vec![0; floats.len()];
+    /// u8::from_component_common_slice(&floats, &mut bytes).unwrap();
+    /// for (float, byte) in floats.iter().zip(&bytes) {
+    ///     assert_eq!(*byte, u8::from_component_common(*float));
+    /// }
+    /// ```
+    fn from_component_common_slice(
+        source: &[T],
+        destination: &mut [Self],
+    ) -> Result<(), LengthMismatchError>
+    where
+        T: Copy,
+        Self: Sized,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        for (source, destination) in source.iter().zip(destination) {
+            *destination = Self::from_component_common(*source);
+        }
+        Ok(())
+    }
 }
 impl<T> FromComponentCommon<T> for T {
     fn from_component_common(component: T) -> Self {
         component
     }
+
+    fn from_component_common_slice(
+        source: &[T],
+        destination: &mut [Self],
+    ) -> Result<(), LengthMismatchError>
+    where
+        T: Copy,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        destination.copy_from_slice(source);
+        Ok(())
+    }
+}
+
+macro_rules! u8_table {
+    ($name:ident, $float:ident) => {
+        // Uses the exact same expression as `float_integer!` so the results are identical.
+        static $name: [$float; 256] = {
+            let mut table = [0.0; 256];
+            let mut i = 0;
+            while i < table.len() {
+                table[i] =
+                    (i as $float - u8::MIN as $float) / (u8::MAX as $float - u8::MIN as $float);
+                i += 1;
+            }
+            table
+        };
+    };
 }
+u8_table!(U8_TO_F32, f32);
+u8_table!(U8_TO_F64, f64);
 
 macro_rules! float_integer {
+    (@to_float $float:ident, $from:ident) => {
+        fn from_component_common(component: $from) -> Self {
+            ((component as $float - $from::MIN as $float)
+                / ($from::MAX as $float - $from::MIN as $float))
+                .clamp(0.0, 1.0)
+        }
+    };
+    (@from_float $float:ident, $from:ident) => {
+        fn from_component_common(component: $float) -> Self {
+            #[allow(unused_imports)]
+            use num_traits::Float;
+
+            (component.clamp(0.0, 1.0)
+                * $float::from($from::MAX as $float - $from::MIN as $float))
+            .round() as $from
+        }
+    };
     ($float:ident, $from:ident) => {
         impl FromComponentCommon<$from> for $float {
-            fn from_component_common(component: $from) -> Self {
-                ((component as $float - $from::MIN as $float)
-                    / ($from::MAX as $float - $from::MIN as $float))
-                    .clamp(0.0, 1.0)
-            }
+            float_integer!(@to_float $float, $from);
         }
         impl FromComponentCommon<$float> for $from {
-            fn from_component_common(component: $float) -> Self {
-                #[allow(unused_imports)]
-                use num_traits::Float;
+            float_integer!(@from_float $float, $from);
+        }
+    };
+    ($float:ident, u8, $table:ident) => {
+        impl FromComponentCommon<u8> for $float {
+            float_integer!(@to_float $float, u8);
+
+            fn from_component_common_slice(
+                source: &[u8],
+                destination: &mut [Self],
+            ) -> Result<(), LengthMismatchError> {
+                LengthMismatchError::check(source.len(), destination.len())?;
+                let vectorized = simd::u8_to_float(source, destination);
+                for (source, destination) in
+                    source[vectorized..].iter().zip(&mut destination[vectorized..])
+                {
+                    *destination = $table[usize::from(*source)];
+                }
+                Ok(())
+            }
+        }
+        impl FromComponentCommon<$float> for u8 {
+            float_integer!(@from_float $float, u8);
 
-                (component.clamp(0.0, 1.0)
-                    * $float::from($from::MAX as $float - $from::MIN as $float))
-                .round() as $from
+            fn from_component_common_slice(
+                source: &[$float],
+                destination: &mut [Self],
+            ) -> Result<(), LengthMismatchError> {
+                LengthMismatchError::check(source.len(), destination.len())?;
+                let vectorized = simd::float_to_u8(source, destination);
+                for (source, destination) in
+                    source[vectorized..].iter().zip(&mut destination[vectorized..])
+                {
+                    *destination = Self::from_component_common(*source);
+                }
+                Ok(())
             }
         }
     };
@@ -56,7 +181,7 @@ impl FromComponentCommon<f64> for f32 {
     }
 }
 
-float_integer!(f32, u8);
+float_integer!(f32, u8, U8_TO_F32);
 float_integer!(f32, u16);
 float_integer!(f32, u32);
 float_integer!(f32, u64);
@@ -69,7 +194,7 @@ float_integer!(f32, i128);
 float_integer!(f32, usize);
 float_integer!(f32, isize);
 
-float_integer!(f64, u8);
+float_integer!(f64, u8, U8_TO_F64);
 float_integer!(f64, u16);
 float_integer!(f64, u32);
 float_integer!(f64, u64);
@@ -0,0 +1,101 @@
+// Conversions for the `f16` and `bf16` types from the `half` crate, which use the same
+// `0.0..=1.0` range as the other floats. Every `f16` and `bf16` is exactly representable as an
+// `f64`, so conversions to and from integers go via `f64` and only round once.
+
+use half::{bf16, f16, slice::HalfFloatSliceExt};
+
+use crate::LengthMismatchError;
+
+use super::FromComponentCommon;
+
+macro_rules! half_float {
+    ($half:ident, $float:ident, $from_float:ident, $to_slice:ident, $from_slice:ident) => {
+        impl FromComponentCommon<$half> for $float {
+            fn from_component_common(component: $half) -> Self {
+                component.into()
+            }
+
+            fn from_component_common_slice(
+                source: &[$half],
+                destination: &mut [Self],
+            ) -> Result<(), LengthMismatchError> {
+                LengthMismatchError::check(source.len(), destination.len())?;
+                source.$to_slice(destination);
+                Ok(())
+            }
+        }
+        impl FromComponentCommon<$float> for $half {
+            fn from_component_common(component: $float) -> Self {
+                $half::$from_float(component)
+            }
+
+            fn from_component_common_slice(
+                source: &[$float],
+                destination: &mut [Self],
+            ) -> Result<(), LengthMismatchError> {
+                LengthMismatchError::check(source.len(), destination.len())?;
+                destination.$from_slice(source);
+                Ok(())
+            }
+        }
+    };
+}
+macro_rules! half_integer {
+    ($half:ident, $int:ident) => {
+        impl FromComponentCommon<$int> for $half {
+            fn from_component_common(component: $int) -> Self {
+                $half::from_f64(f64::from_component_common(component))
+            }
+        }
+        impl FromComponentCommon<$half> for $int {
+            fn from_component_common(component: $half) -> Self {
+                Self::from_component_common(f64::from(component))
+            }
+        }
+    };
+}
+macro_rules! half {
+    ($half:ident) => {
+        half_float!(
+            $half,
+            f32,
+            from_f32,
+            convert_to_f32_slice,
+            convert_from_f32_slice
+        );
+        half_float!(
+            $half,
+            f64,
+            from_f64,
+            convert_to_f64_slice,
+            convert_from_f64_slice
+        );
+
+        half_integer!($half, u8);
+        half_integer!($half, u16);
+        half_integer!($half, u32);
+        half_integer!($half, u64);
+        half_integer!($half, u128);
+        half_integer!($half, i8);
+        half_integer!($half, i16);
+        half_integer!($half, i32);
+        half_integer!($half, i64);
+        half_integer!($half, i128);
+        half_integer!($half, usize);
+        half_integer!($half, isize);
+    };
+}
+
+half!(f16);
+half!(bf16);
+
+impl FromComponentCommon<f16> for bf16 {
+    fn from_component_common(component: f16) -> Self {
+        bf16::from_f32(component.into())
+    }
+}
+impl FromComponentCommon<bf16> for f16 {
+    fn from_component_common(component: bf16) -> Self {
+        f16::from_f32(component.into())
+    }
+}
@@ -0,0 +1,151 @@
+// Conversions for the `Unorm` and `Snorm` normalized components.
+//
+// Every component type is converted to and from a normalized `f64`, which is `0.0..=1.0` for
+// `Unorm`s, integers and floats, and `-1.0..=1.0` for `Snorm`s. Values outside the range of the
+// destination are clamped, except for floats which keep the range of the source.
+
+use crate::*;
+
+use super::FromComponentCommon;
+
+trait Normalized {
+    fn to_normalized(self) -> f64;
+    fn from_normalized(normalized: f64) -> Self;
+}
+
+macro_rules! normalized_primitive {
+    ($primitive:ty) => {
+        impl Normalized for $primitive {
+            fn to_normalized(self) -> f64 {
+                f64::from_component_common(self)
+            }
+            fn from_normalized(normalized: f64) -> Self {
+                Self::from_component_common(normalized)
+            }
+        }
+    };
+}
+macro_rules! normalized_unorm {
+    ($int:ident) => {
+        impl<const BITS: u32> Normalized for Unorm<$int, BITS> {
+            fn to_normalized(self) -> f64 {
+                self.0 as f64 / Self::COMPONENT_MAX.0 as f64
+            }
+            fn from_normalized(normalized: f64) -> Self {
+                Unorm(libm::round(normalized.clamp(0.0, 1.0) * Self::COMPONENT_MAX.0 as f64) as $int)
+            }
+        }
+
+        primitives!([const BITS: u32], Unorm<$int, BITS>);
+    };
+}
+macro_rules! normalized_snorm {
+    ($int:ident) => {
+        impl Normalized for Snorm<$int> {
+            fn to_normalized(self) -> f64 {
+                (self.0 as f64 / $int::MAX as f64).max(-1.0)
+            }
+            fn from_normalized(normalized: f64) -> Self {
+                Snorm(libm::round(normalized.clamp(-1.0, 1.0) * $int::MAX as f64) as $int)
+            }
+        }
+
+        primitives!([], Snorm<$int>);
+    };
+}
+
+macro_rules! normalized_normalized {
+    ([$($generics:tt)*], $normalized1:ty, $normalized2:ty) => {
+        impl<$($generics)*> FromComponentCommon<$normalized1> for $normalized2 {
+            fn from_component_common(component: $normalized1) -> Self {
+                Self::from_normalized(component.to_normalized())
+            }
+        }
+        impl<$($generics)*> FromComponentCommon<$normalized2> for $normalized1 {
+            fn from_component_common(component: $normalized2) -> Self {
+                Self::from_normalized(component.to_normalized())
+            }
+        }
+    };
+}
+macro_rules! primitives {
+    ([$($generics:tt)*], $normalized:ty) => {
+        normalized_normalized!([$($generics)*], $normalized, u8);
+        normalized_normalized!([$($generics)*], $normalized, u16);
+        normalized_normalized!([$($generics)*], $normalized, u32);
+        normalized_normalized!([$($generics)*], $normalized, u64);
+        normalized_normalized!([$($generics)*], $normalized, u128);
+        normalized_normalized!([$($generics)*], $normalized, i8);
+        normalized_normalized!([$($generics)*], $normalized, i16);
+        normalized_normalized!([$($generics)*], $normalized, i32);
+        normalized_normalized!([$($generics)*], $normalized, i64);
+        normalized_normalized!([$($generics)*], $normalized, i128);
+        normalized_normalized!([$($generics)*], $normalized, usize);
+        normalized_normalized!([$($generics)*], $normalized, isize);
+        normalized_normalized!([$($generics)*], $normalized, f32);
+        normalized_normalized!([$($generics)*], $normalized, f64);
+        #[cfg(feature = "half")]
+        normalized_normalized!([$($generics)*], $normalized, half::f16);
+        #[cfg(feature = "half")]
+        normalized_normalized!([$($generics)*], $normalized, half::bf16);
+    };
+}
+
+normalized_primitive!(u8);
+normalized_primitive!(u16);
+normalized_primitive!(u32);
+normalized_primitive!(u64);
+normalized_primitive!(u128);
+normalized_primitive!(i8);
+normalized_primitive!(i16);
+normalized_primitive!(i32);
+normalized_primitive!(i64);
+normalized_primitive!(i128);
+normalized_primitive!(usize);
+normalized_primitive!(isize);
+normalized_primitive!(f32);
+normalized_primitive!(f64);
+#[cfg(feature = "half")]
+normalized_primitive!(half::f16);
+#[cfg(feature = "half")]
+normalized_primitive!(half::bf16);
+
+normalized_unorm!(u8);
+normalized_unorm!(u16);
+normalized_unorm!(u32);
+normalized_unorm!(u64);
+normalized_snorm!(i8);
+normalized_snorm!(i16);
+normalized_snorm!(i32);
+normalized_snorm!(i64);
+
+normalized_normalized!([const BITS1: u32, const BITS2: u32], Unorm<u8, BITS1>, Unorm<u16, BITS2>);
+normalized_normalized!([const BITS1: u32, const BITS2: u32], Unorm<u8, BITS1>, Unorm<u32, BITS2>);
+normalized_normalized!([const BITS1: u32, const BITS2: u32], Unorm<u8, BITS1>, Unorm<u64, BITS2>);
+normalized_normalized!([const BITS1: u32, const BITS2: u32], Unorm<u16, BITS1>, Unorm<u32, BITS2>);
+normalized_normalized!([const BITS1: u32, const BITS2: u32], Unorm<u16, BITS1>, Unorm<u64, BITS2>);
+normalized_normalized!([const BITS1: u32, const BITS2: u32], Unorm<u32, BITS1>, Unorm<u64, BITS2>);
+
+normalized_normalized!([], Snorm<i8>, Snorm<i16>);
+normalized_normalized!([], Snorm<i8>, Snorm<i32>);
+normalized_normalized!([], Snorm<i8>, Snorm<i64>);
+normalized_normalized!([], Snorm<i16>, Snorm<i32>);
+normalized_normalized!([], Snorm<i16>, Snorm<i64>);
+normalized_normalized!([], Snorm<i32>, Snorm<i64>);
+
+normalized_normalized!([const BITS: u32], Unorm<u8, BITS>, Snorm<i8>);
+normalized_normalized!([const BITS: u32], Unorm<u8, BITS>, Snorm<i16>);
+normalized_normalized!([const BITS: u32], Unorm<u8, BITS>, Snorm<i32>);
+normalized_normalized!([const BITS: u32], Unorm<u8, BITS>, Snorm<i64>);
+normalized_normalized!([const BITS: u32], Unorm<u16, BITS>, Snorm<i8>);
+normalized_normalized!([const BITS: u32], Unorm<u16, BITS>, Snorm<i16>);
+normalized_normalized!([const BITS: u32], Unorm<u16, BITS>, Snorm<i32>);
+normalized_normalized!([const BITS: u32], Unorm<u16, BITS>, Snorm<i64>);
+normalized_normalized!([const BITS: u32], Unorm<u32, BITS>, Snorm<i8>);
+normalized_normalized!([const BITS: u32], Unorm<u32, BITS>, Snorm<i16>);
+normalized_normalized!([const BITS: u32], Unorm<u32, BITS>, Snorm<i32>);
+normalized_normalized!([const BITS: u32], Unorm<u32, BITS>, Snorm<i64>);
+normalized_normalized!([const BITS: u32], Unorm<u64, BITS>, Snorm<i8>);
+normalized_normalized!([const BITS: u32], Unorm<u64, BITS>, Snorm<i16>);
+normalized_normalized!([const BITS: u32], Unorm<u64, BITS>, Snorm<i32>);
+normalized_normalized!([const BITS: u32], Unorm<u64, BITS>, Snorm<i64>);
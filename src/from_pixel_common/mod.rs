@@ -1,14 +1,22 @@
 use crate::*;
 
 use self::{
-    enlargeable::Enlargeable, from_component_common::FromComponentCommon, to_gray::ToGray,
+    enlargeable::Enlargeable,
+    from_component_common::FromComponentCommon,
+    to_gray::{Luma, ToGray},
     to_rgb::ToRgb,
 };
 
+pub mod chromatic_adaptation;
+mod cylindrical;
 pub mod enlargeable;
 pub mod from_component_common;
+mod packed;
+pub mod perceptual;
+pub(crate) mod simd;
 mod to_gray;
 mod to_rgb;
+pub mod y_cb_cr;
 
 /// Convert between pixel types using the most common conversion method, this conversion can be
 /// lossy, such as from [`Rgb`] to [`Gray`].
@@ -20,6 +28,9 @@ mod to_rgb;
 ///
 /// This trait can convert from any of the pixel types in this crate with a primitive generic
 /// component to any other pixel type in this crate with any other primitive generic component type.
+/// The exceptions are the cylindrical pixel types such as [`Hsl`] and the perceptual pixel types
+/// such as [`Lab`], which only convert to and from the RGB family and their own family. The
+/// perceptual pixel types also only support float components.
 ///
 /// # Examples
 /// ```
@@ -29,10 +40,87 @@ mod to_rgb;
 /// let _: Rgba<f32> = Rgba::from_pixel_common(Gray::<i8> { gray: 100 });
 /// let _: GrayAlpha<u8> = GrayAlpha::from_pixel_common(Bgr::<f32> { b: 0.3, g: 0.6, r: 0.9 });
 /// let _: Gray<i8> = Gray::from_pixel_common(Rgba::<f64> { r: 0.4, g: 0.7, b: 0.7, a: 0.5 });
+/// let _: Hsla<u8> = Hsla::from_pixel_common(Rgb::<u8> { r: 255, g: 128, b: 0 });
 /// ```
 pub trait FromPixelCommon<P> {
     /// Converts the given pixel type to the `Self` type.
     fn from_pixel_common(pixel: P) -> Self;
+
+    /// Converts each pixel in `source` into the same position in `destination`.
+    ///
+    /// The result is identical to calling [`FromPixelCommon::from_pixel_common()`] on each pixel,
+    /// but conversions between pixel types with the same layout and conversions to gray are
+    /// faster than doing so. Converting four component `u8` pixels to [`Gray<u8>`] or swapping
+    /// their red and blue components, such as from [`Bgra<u8>`] to [`Rgba<u8>`], is vectorized
+    /// on `x86_64` and `aarch64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices are
+    /// not the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let frame: Vec<_> = (0..1000_u32)
+    ///     .map(|i| Bgra::<u8> { b: i as u8, g: (i * 7) as u8, r: (i * 13) as u8, a: 255 })
+    ///     .collect();
+    ///
+    /// let mut rgba = vec![Rgba::<u8>::default(); frame.len()];
+    /// Rgba::from_pixel_common_slice(&frame, &mut rgba).unwrap();
+    ///
+    /// let mut gray = vec![Gray::<u8>::default(); frame.len()];
+    /// Gray::from_pixel_common_slice(&frame, &mut gray).unwrap();
+    ///
+    /// for ((bgra, rgba), gray) in frame.iter().zip(&rgba).zip(&gray) {
+    ///     assert_eq!(*rgba, Rgba::from_pixel_common(*bgra));
+    ///     assert_eq!(*gray, Gray::from_pixel_common(*bgra));
+    /// }
+    /// ```
+    fn from_pixel_common_slice(
+        source: &[P],
+        destination: &mut [Self],
+    ) -> Result<(), LengthMismatchError>
+    where
+        P: Copy,
+        Self: Sized,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        for (source, destination) in source.iter().zip(destination) {
+            *destination = Self::from_pixel_common(*source);
+        }
+        Ok(())
+    }
+}
+
+/// Converts each pixel in `source` into the same position in `destination` using
+/// [`FromPixelCommon`].
+///
+/// # Errors
+///
+/// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices are not
+/// the same length.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let frame = [Rgba::<u8> { r: 255, g: 128, b: 0, a: 255 }; 4];
+/// let mut gray = [Gray::<f32> { gray: 0.0 }; 4];
+///
+/// convert_slice(&frame, &mut gray).unwrap();
+/// assert_eq!(gray[0], Gray::from_pixel_common(frame[0]));
+///
+/// let mut too_short = [Gray::<f32> { gray: 0.0 }; 3];
+/// assert!(convert_slice(&frame, &mut too_short).is_err());
+/// ```
+pub fn convert_slice<P, Q>(source: &[P], destination: &mut [Q]) -> Result<(), LengthMismatchError>
+where
+    P: Copy,
+    Q: FromPixelCommon<P>,
+{
+    Q::from_pixel_common_slice(source, destination)
 }
 
 fn lossless<P, Q>(pixel: P) -> Q
@@ -57,6 +145,23 @@ where
     let with_alpha = converted.with_alpha();
     Q::from(with_alpha)
 }
+fn lossless_slice<P, Q>(source: &[P], destination: &mut [Q]) -> Result<(), LengthMismatchError>
+where
+    P: Pixel,
+    Q: Pixel,
+    Q::Component: FromComponentCommon<P::Component>,
+    Q: From<P::SelfType<Q::Component>>,
+{
+    LengthMismatchError::check(source.len(), destination.len())?;
+    let vectorized = simd::swap_red_blue(source, destination);
+    for (source, destination) in source[vectorized..]
+        .iter()
+        .zip(&mut destination[vectorized..])
+    {
+        *destination = lossless(*source);
+    }
+    Ok(())
+}
 fn to_gray<P, Q>(pixel: P) -> Q
 where
     P: Pixel + ToGray,
@@ -81,6 +186,38 @@ where
     let with_alpha = converted.with_alpha();
     Q::from(with_alpha)
 }
+fn same_layout_slice<P, Q>(source: &[P], destination: &mut [Q]) -> Result<(), LengthMismatchError>
+where
+    P: ContiguousPixel,
+    Q: ContiguousPixel,
+    Q::Component: FromComponentCommon<P::Component>,
+{
+    LengthMismatchError::check(source.len(), destination.len())?;
+    Q::Component::from_component_common_slice(
+        P::slice_to_components(source),
+        Q::slice_to_components_mut(destination),
+    )
+}
+fn to_gray_slice<P, Q>(source: &[P], destination: &mut [Q]) -> Result<(), LengthMismatchError>
+where
+    P: Pixel + ToGray,
+    Q: Pixel,
+    Q::Component: FromComponentCommon<<P::Gray as Pixel>::Component>,
+    Q: From<<P::Gray as Pixel>::SelfType<Q::Component>>,
+{
+    LengthMismatchError::check(source.len(), destination.len())?;
+    let vectorized = simd::to_gray(source, destination);
+    let luma = Luma::new();
+    for (source, destination) in source[vectorized..]
+        .iter()
+        .zip(&mut destination[vectorized..])
+    {
+        let gray = source.to_gray_with(&luma);
+        let converted = gray.map_components(Q::Component::from_component_common);
+        *destination = Q::from(converted);
+    }
+    Ok(())
+}
 fn to_rgb<P, Q>(pixel: P) -> Q
 where
     P: Pixel + ToRgb,
@@ -107,7 +244,7 @@ where
 }
 
 macro_rules! from_pixel_common {
-    ($from:ident, $into:ident, $method:ident) => {
+    ($from:ident, $into:ident, $method:ident $(, $slice_method:ident)?) => {
         impl<R, S> FromPixelCommon<$from<R>> for $into<S>
         where
             R: PixelComponent + Enlargeable,
@@ -116,13 +253,26 @@ macro_rules! from_pixel_common {
             fn from_pixel_common(pixel: $from<R>) -> Self {
                 $method(pixel)
             }
+            $(
+                fn from_pixel_common_slice(
+                    source: &[$from<R>],
+                    destination: &mut [Self],
+                ) -> Result<(), LengthMismatchError> {
+                    $slice_method(source, destination)
+                }
+            )?
         }
     };
 }
 
 macro_rules! lossless {
     ($from:ident, $into:ident) => {
-        from_pixel_common!($from, $into, lossless);
+        from_pixel_common!($from, $into, lossless, lossless_slice);
+    };
+}
+macro_rules! same_layout {
+    ($both:ident) => {
+        from_pixel_common!($both, $both, lossless, same_layout_slice);
     };
 }
 macro_rules! lossless_with_alpha {
@@ -132,7 +282,7 @@ macro_rules! lossless_with_alpha {
 }
 macro_rules! to_gray {
     ($from:ident, $into:ident) => {
-        from_pixel_common!($from, $into, to_gray);
+        from_pixel_common!($from, $into, to_gray, to_gray_slice);
     };
 }
 macro_rules! to_gray_with_alpha {
@@ -151,7 +301,29 @@ macro_rules! to_rgb_with_alpha {
     };
 }
 
-lossless!(Rgb, Rgb);
+// Converting the wrapped pixels is correct for linear pixels since the common component ranges
+// are related linearly, and converting to gray then gives the true CIE Y luminance.
+impl<P, Q> FromPixelCommon<Linear<P>> for Linear<Q>
+where
+    P: Copy,
+    Q: FromPixelCommon<P>,
+{
+    fn from_pixel_common(pixel: Linear<P>) -> Self {
+        Linear(Q::from_pixel_common(pixel.0))
+    }
+
+    fn from_pixel_common_slice(
+        source: &[Linear<P>],
+        destination: &mut [Self],
+    ) -> Result<(), LengthMismatchError> {
+        //This is safe since `Linear` is #[repr(transparent)].
+        let source = unsafe { &*(source as *const [Linear<P>] as *const [P]) };
+        let destination = unsafe { &mut *(destination as *mut [Linear<Q>] as *mut [Q]) };
+        Q::from_pixel_common_slice(source, destination)
+    }
+}
+
+same_layout!(Rgb);
 lossless!(Rgb, Bgr);
 lossless_with_alpha!(Rgb, Rgba);
 lossless_with_alpha!(Rgb, Argb);
@@ -161,7 +333,7 @@ to_gray!(Rgb, Gray);
 to_gray_with_alpha!(Rgb, GrayAlpha);
 
 lossless!(Bgr, Rgb);
-lossless!(Bgr, Bgr);
+same_layout!(Bgr);
 lossless_with_alpha!(Bgr, Rgba);
 lossless_with_alpha!(Bgr, Argb);
 lossless_with_alpha!(Bgr, Bgra);
@@ -171,7 +343,7 @@ to_gray_with_alpha!(Bgr, GrayAlpha);
 
 lossless!(Rgba, Rgb);
 lossless!(Rgba, Bgr);
-lossless!(Rgba, Rgba);
+same_layout!(Rgba);
 lossless!(Rgba, Argb);
 lossless!(Rgba, Bgra);
 lossless!(Rgba, Abgr);
@@ -181,7 +353,7 @@ to_gray!(Rgba, GrayAlpha);
 lossless!(Argb, Rgb);
 lossless!(Argb, Bgr);
 lossless!(Argb, Rgba);
-lossless!(Argb, Argb);
+same_layout!(Argb);
 lossless!(Argb, Bgra);
 lossless!(Argb, Abgr);
 to_gray!(Argb, Gray);
@@ -191,7 +363,7 @@ lossless!(Bgra, Rgb);
 lossless!(Bgra, Bgr);
 lossless!(Bgra, Rgba);
 lossless!(Bgra, Argb);
-lossless!(Bgra, Bgra);
+same_layout!(Bgra);
 lossless!(Bgra, Abgr);
 to_gray!(Bgra, Gray);
 to_gray!(Bgra, GrayAlpha);
@@ -201,7 +373,7 @@ lossless!(Abgr, Bgr);
 lossless!(Abgr, Rgba);
 lossless!(Abgr, Argb);
 lossless!(Abgr, Bgra);
-lossless!(Abgr, Abgr);
+same_layout!(Abgr);
 to_gray!(Abgr, Gray);
 to_gray!(Abgr, GrayAlpha);
 
@@ -211,7 +383,7 @@ to_rgb_with_alpha!(Gray, Rgba);
 to_rgb_with_alpha!(Gray, Argb);
 to_rgb_with_alpha!(Gray, Bgra);
 to_rgb_with_alpha!(Gray, Abgr);
-lossless!(Gray, Gray);
+same_layout!(Gray);
 lossless_with_alpha!(Gray, GrayAlpha);
 
 to_rgb!(GrayAlpha, Rgb);
@@ -221,4 +393,22 @@ to_rgb!(GrayAlpha, Argb);
 to_rgb!(GrayAlpha, Bgra);
 to_rgb!(GrayAlpha, Abgr);
 lossless!(GrayAlpha, Gray);
-lossless!(GrayAlpha, GrayAlpha);
+same_layout!(GrayAlpha);
+
+same_layout!(Hsl);
+lossless_with_alpha!(Hsl, Hsla);
+
+lossless!(Hsla, Hsl);
+same_layout!(Hsla);
+
+same_layout!(Hsv);
+lossless_with_alpha!(Hsv, Hsva);
+
+lossless!(Hsva, Hsv);
+same_layout!(Hsva);
+
+same_layout!(Hwb);
+lossless_with_alpha!(Hwb, Hwba);
+
+lossless!(Hwba, Hwb);
+same_layout!(Hwba);
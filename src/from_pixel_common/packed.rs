@@ -0,0 +1,89 @@
+// Conversions between the RGB family of pixels and the packed pixels.
+//
+// All conversions go via `Rgba<f64>`, where each packed component is divided by the maximum
+// value of its bit width, so expansion and compression round exactly for every component type.
+// Packed pixels without alpha are opaque and drop alpha when converted to.
+
+use crate::*;
+
+use super::{enlargeable::Enlargeable, from_component_common::FromComponentCommon};
+
+fn expand(value: u32, bits: u32) -> f64 {
+    f64::from(value) / f64::from((1 << bits) - 1)
+}
+fn compress(value: f64, bits: u32) -> u32 {
+    let max = f64::from((1 << bits) - 1);
+    libm::round(value.clamp(0.0, 1.0) * max) as u32
+}
+
+trait Packed {
+    fn to_rgba(self) -> Rgba<f64>;
+    fn from_rgba(rgba: Rgba<f64>) -> Self;
+}
+
+// The bit widths come from the `packed!` macro defining each type, only whether there is an alpha
+// component is repeated here.
+macro_rules! packed {
+    ($name:ident, $int:ident $(, $a:ident)?) => {
+        impl Packed for $name {
+            fn to_rgba(self) -> Rgba<f64> {
+                let bits = Self::BITS;
+                #[allow(unused_mut, unused_assignments)]
+                let mut alpha = 1.0;
+                $(alpha = expand(self.$a().into(), bits.$a);)?
+
+                Rgba {
+                    r: expand(self.r().into(), bits.r),
+                    g: expand(self.g().into(), bits.g),
+                    b: expand(self.b().into(), bits.b),
+                    a: alpha,
+                }
+            }
+            fn from_rgba(rgba: Rgba<f64>) -> Self {
+                let bits = Self::BITS;
+                Self::new(
+                    compress(rgba.r, bits.r) as $int,
+                    compress(rgba.g, bits.g) as $int,
+                    compress(rgba.b, bits.b) as $int,
+                    $(compress(rgba.$a, bits.$a) as $int,)?
+                )
+            }
+        }
+
+        rgb_packed!(Rgb, $name);
+        rgb_packed!(Bgr, $name);
+        rgb_packed!(Rgba, $name);
+        rgb_packed!(Argb, $name);
+        rgb_packed!(Bgra, $name);
+        rgb_packed!(Abgr, $name);
+    };
+}
+
+macro_rules! rgb_packed {
+    ($rgb:ident, $packed:ident) => {
+        impl<T> FromPixelCommon<$packed> for $rgb<T>
+        where
+            T: PixelComponent + FromComponentCommon<f64>,
+        {
+            fn from_pixel_common(pixel: $packed) -> Self {
+                Self::from_pixel_common(pixel.to_rgba())
+            }
+        }
+        impl<T> FromPixelCommon<$rgb<T>> for $packed
+        where
+            T: PixelComponent + Enlargeable,
+            f64: FromComponentCommon<T>,
+        {
+            fn from_pixel_common(pixel: $rgb<T>) -> Self {
+                Self::from_rgba(Rgba::from_pixel_common(pixel))
+            }
+        }
+    };
+}
+
+packed!(Rgb565, u16);
+packed!(Bgr565, u16);
+packed!(Rgba4444, u16, a);
+packed!(Argb1555, u16, a);
+packed!(Rgba5551, u16, a);
+packed!(Rgb10a2, u32, a);
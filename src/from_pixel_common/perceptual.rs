@@ -0,0 +1,472 @@
+// Conversions between the RGB family of pixels and the perceptual `Xyz`, `Lab`, `Lch`, `Oklab`
+// and `Oklch` families of pixels.
+//
+// Perceptual pixels use their natural units rather than the common component ranges, so they
+// only support float components. RGB pixels are assumed to be sRGB encoded and are converted via
+// linear sRGB, with the D65 white point used for `Lab` and `Lch` unless converted with
+// `Lab::from_srgb()` and `Lab::to_srgb()`.
+
+use num_traits::Float;
+
+use crate::*;
+
+pub(super) type Matrix = [[f64; 3]; 3];
+
+/// Linear sRGB to CIE XYZ, derived from the sRGB primaries and the [`WhitePoint::D65`] white
+/// point.
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.4123907992659595, 0.357584339383878, 0.1804807884018343],
+    [0.2126390058715104, 0.7151686787677559, 0.0721923153607337],
+    [0.0193308187155918, 0.119194779794626, 0.9505321522496606],
+];
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045213, -1.5373831775700935, -0.4986107602930033],
+    [-0.9692436362808798, 1.8759675015077206, 0.0415550574071756],
+    [0.0556300796969936, -0.2039769588889766, 1.0569715142428786],
+];
+
+/// Linear sRGB to Oklab's cone responses and back, from <https://bottosson.github.io/posts/oklab/>.
+const LINEAR_SRGB_TO_LMS: Matrix = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+const LMS_TO_LINEAR_SRGB: Matrix = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+/// Non-linear cone responses to Oklab and back.
+const LMS_TO_OKLAB: Matrix = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+/// XYZ to the cone responses of the Bradford chromatic adaptation transform, used by ICC profiles.
+pub(super) const BRADFORD: Matrix = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// A reference white, given by its CIE xy chromaticity coordinates.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let lab = Lab::from_xyz(WhitePoint::D50.xyz::<f64>(), WhitePoint::D50);
+///
+/// assert!((lab.l - 100.0).abs() < 1e-9);
+/// assert!(lab.a.abs() < 1e-9 && lab.b.abs() < 1e-9);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WhitePoint {
+    /// The x chromaticity coordinate.
+    pub x: f64,
+    /// The y chromaticity coordinate.
+    pub y: f64,
+}
+impl WhitePoint {
+    /// The CIE standard illuminant D65, used by sRGB.
+    pub const D65: Self = Self {
+        x: 0.3127,
+        y: 0.3290,
+    };
+    /// The CIE standard illuminant D50, used by ICC profiles and printing.
+    pub const D50: Self = Self {
+        x: 0.34567,
+        y: 0.35850,
+    };
+
+    /// Returns the white point of a light source with the given correlated color temperature in
+    /// kelvin, clamped to the range from 1667 K to 25000 K.
+    ///
+    /// Temperatures from 4000 K follow the CIE daylight locus which the D illuminants lie on,
+    /// while lower temperatures follow the Planckian locus of incandescent light using the
+    /// approximation from Kim et al., "Design of Advanced Color Temperature Control System for
+    /// HDTV Applications" (2002).
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let d65 = WhitePoint::from_temperature(6504.0);
+    /// assert!((d65.x - WhitePoint::D65.x).abs() < 5e-4 && (d65.y - WhitePoint::D65.y).abs() < 5e-4);
+    ///
+    /// // The color of a tungsten bulb.
+    /// let tungsten = Rgb::<u8>::from_pixel_common(WhitePoint::from_temperature(2700.0).xyz::<f64>());
+    /// assert!(tungsten.r > tungsten.g && tungsten.g > tungsten.b);
+    /// ```
+    pub fn from_temperature(kelvin: f64) -> Self {
+        let t = kelvin.clamp(1667.0, 25000.0);
+        let (t2, t3) = (t * t, t * t * t);
+
+        if t >= 4000.0 {
+            let x = if t <= 7000.0 {
+                -4.6070e9 / t3 + 2.9678e6 / t2 + 0.09911e3 / t + 0.244063
+            } else {
+                -2.0064e9 / t3 + 1.9018e6 / t2 + 0.24748e3 / t + 0.237040
+            };
+            let y = -3.0 * x * x + 2.870 * x - 0.275;
+            return Self { x, y };
+        }
+
+        let x = -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910;
+        let (x2, x3) = (x * x, x * x * x);
+        let y = if t <= 2222.0 {
+            -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+        } else {
+            -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+        };
+        Self { x, y }
+    }
+
+    /// Returns the white point as an [`Xyz`] pixel with a luminance of one.
+    pub fn xyz<T>(self) -> Xyz<T>
+    where
+        T: PixelComponent + Float,
+    {
+        Xyz::from_colors_alpha(self.xyz_f64().map(cast), T::COMPONENT_MAX)
+    }
+
+    pub(super) fn xyz_f64(self) -> [f64; 3] {
+        [self.x / self.y, 1.0, (1.0 - self.x - self.y) / self.y]
+    }
+}
+
+fn cast<T, U>(x: T) -> U
+where
+    T: Float,
+    U: Float,
+{
+    num_traits::cast(x).unwrap()
+}
+pub(super) fn multiply(matrix: &Matrix, vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+fn product(a: &Matrix, b: &Matrix) -> Matrix {
+    core::array::from_fn(|i| core::array::from_fn(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum()))
+}
+fn invert(m: &Matrix) -> Matrix {
+    // The transpose of the cofactors divided by the determinant.
+    let cofactor = |i: usize, j: usize| {
+        let [r0, r1] = [(i + 1) % 3, (i + 2) % 3];
+        let [c0, c1] = [(j + 1) % 3, (j + 2) % 3];
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+    let determinant: f64 = (0..3).map(|j| m[0][j] * cofactor(0, j)).sum();
+    core::array::from_fn(|i| core::array::from_fn(|j| cofactor(j, i) / determinant))
+}
+
+/// Returns the matrix adapting XYZ colors seen under the `source` white point to the colors that
+/// look the same under the `destination` white point, by scaling the cone responses given by
+/// `to_cone` by the ratio between the white points.
+pub(super) fn adaptation(to_cone: &Matrix, source: WhitePoint, destination: WhitePoint) -> Matrix {
+    let source = multiply(to_cone, source.xyz_f64());
+    let destination = multiply(to_cone, destination.xyz_f64());
+    let scaled = core::array::from_fn(|i| to_cone[i].map(|x| x * destination[i] / source[i]));
+
+    product(&invert(to_cone), &scaled)
+}
+
+fn xyz_to_lab(xyz: [f64; 3], white_point: WhitePoint) -> [f64; 3] {
+    const DELTA: f64 = 6.0 / 29.0;
+    let f = |t: f64| {
+        if t > DELTA * DELTA * DELTA {
+            libm::cbrt(t)
+        } else {
+            t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+        }
+    };
+
+    let white = white_point.xyz_f64();
+    let [fx, fy, fz] = [0, 1, 2].map(|i| f(xyz[i] / white[i]));
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+fn lab_to_xyz([l, a, b]: [f64; 3], white_point: WhitePoint) -> [f64; 3] {
+    const DELTA: f64 = 6.0 / 29.0;
+    let f = |t: f64| {
+        if t > DELTA {
+            t * t * t
+        } else {
+            3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+        }
+    };
+
+    let fy = (l + 16.0) / 116.0;
+    let white = white_point.xyz_f64();
+    let f = [fy + a / 500.0, fy, fy - b / 200.0].map(f);
+
+    [0, 1, 2].map(|i| f[i] * white[i])
+}
+
+fn rectangular_to_polar([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let h = libm::atan2(b, a).to_degrees();
+    let h = if h < 0.0 { h + 360.0 } else { h };
+
+    [l, libm::hypot(a, b), h]
+}
+fn polar_to_rectangular([l, c, h]: [f64; 3]) -> [f64; 3] {
+    let h = h.to_radians();
+
+    [l, c * libm::cos(h), c * libm::sin(h)]
+}
+
+fn linear_srgb_to_xyz(rgb: [f64; 3]) -> [f64; 3] {
+    multiply(&LINEAR_SRGB_TO_XYZ, rgb)
+}
+fn xyz_to_linear_srgb(xyz: [f64; 3]) -> [f64; 3] {
+    multiply(&XYZ_TO_LINEAR_SRGB, xyz)
+}
+fn linear_srgb_to_lab(rgb: [f64; 3]) -> [f64; 3] {
+    xyz_to_lab(linear_srgb_to_xyz(rgb), WhitePoint::D65)
+}
+fn lab_to_linear_srgb(lab: [f64; 3]) -> [f64; 3] {
+    xyz_to_linear_srgb(lab_to_xyz(lab, WhitePoint::D65))
+}
+fn linear_srgb_to_lch(rgb: [f64; 3]) -> [f64; 3] {
+    rectangular_to_polar(linear_srgb_to_lab(rgb))
+}
+fn lch_to_linear_srgb(lch: [f64; 3]) -> [f64; 3] {
+    lab_to_linear_srgb(polar_to_rectangular(lch))
+}
+fn linear_srgb_to_oklab(rgb: [f64; 3]) -> [f64; 3] {
+    let lms = multiply(&LINEAR_SRGB_TO_LMS, rgb).map(libm::cbrt);
+    multiply(&LMS_TO_OKLAB, lms)
+}
+fn oklab_to_linear_srgb(oklab: [f64; 3]) -> [f64; 3] {
+    let lms = multiply(&OKLAB_TO_LMS, oklab).map(|x| x * x * x);
+    multiply(&LMS_TO_LINEAR_SRGB, lms)
+}
+fn linear_srgb_to_oklch(rgb: [f64; 3]) -> [f64; 3] {
+    rectangular_to_polar(linear_srgb_to_oklab(rgb))
+}
+fn oklch_to_linear_srgb(oklch: [f64; 3]) -> [f64; 3] {
+    oklab_to_linear_srgb(polar_to_rectangular(oklch))
+}
+
+impl<T> Lab<T>
+where
+    T: PixelComponent + Float,
+{
+    /// Converts from an [`Xyz`] pixel relative to the given reference white.
+    ///
+    /// Changing the reference white does not perform any chromatic adaptation, so `xyz` must
+    /// already be relative to `white_point`.
+    pub fn from_xyz(xyz: Xyz<T>, white_point: WhitePoint) -> Self {
+        let lab = xyz_to_lab(xyz.color_array().map(cast), white_point);
+        Self::from_colors_alpha(lab.map(cast), T::COMPONENT_MAX)
+    }
+    /// Converts to an [`Xyz`] pixel relative to the given reference white.
+    pub fn to_xyz(self, white_point: WhitePoint) -> Xyz<T> {
+        let xyz = lab_to_xyz(self.color_array().map(cast), white_point);
+        Xyz::from_colors_alpha(xyz.map(cast), T::COMPONENT_MAX)
+    }
+
+    /// Converts from a pixel of the RGB family relative to the given reference white.
+    ///
+    /// Unlike [`FromPixelCommon`], which always uses the [`WhitePoint::D65`] white of sRGB, the
+    /// color is adapted from [`WhitePoint::D65`] to `white_point` with the Bradford transform used
+    /// by ICC profiles, so that white always has an `a` and `b` of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let white = Rgb::<u8> { r: 255, g: 255, b: 255 };
+    ///
+    /// let lab = Lab::<f64>::from_srgb(white, WhitePoint::D50);
+    /// assert!((lab.l - 100.0).abs() < 1e-6 && lab.a.abs() < 1e-6 && lab.b.abs() < 1e-6);
+    ///
+    /// let rgb: Rgb<u8> = lab.to_srgb(WhitePoint::D50);
+    /// assert_eq!(rgb, white);
+    /// ```
+    pub fn from_srgb<P>(pixel: P, white_point: WhitePoint) -> Self
+    where
+        Xyz<f64>: FromPixelCommon<P>,
+    {
+        let xyz = Xyz::<f64>::from_pixel_common(pixel).color_array();
+        let xyz = multiply(&adaptation(&BRADFORD, WhitePoint::D65, white_point), xyz);
+        let lab = xyz_to_lab(xyz, white_point);
+        Self::from_colors_alpha(lab.map(cast), T::COMPONENT_MAX)
+    }
+    /// Converts to a pixel of the RGB family relative to the given reference white, as in
+    /// [`Lab::from_srgb()`].
+    pub fn to_srgb<P>(self, white_point: WhitePoint) -> P
+    where
+        P: FromPixelCommon<Xyz<f64>>,
+    {
+        let xyz = lab_to_xyz(self.color_array().map(cast), white_point);
+        let xyz = multiply(&adaptation(&BRADFORD, white_point, WhitePoint::D65), xyz);
+        P::from_pixel_common(Xyz::from_colors_alpha(xyz, 1.0))
+    }
+}
+impl<T> Lch<T>
+where
+    T: PixelComponent + Float,
+{
+    /// Converts from the rectangular [`Lab`] form.
+    pub fn from_lab(lab: Lab<T>) -> Self {
+        let lch = rectangular_to_polar(lab.color_array().map(cast));
+        Self::from_colors_alpha(lch.map(cast), T::COMPONENT_MAX)
+    }
+    /// Converts to the rectangular [`Lab`] form.
+    pub fn to_lab(self) -> Lab<T> {
+        let lab = polar_to_rectangular(self.color_array().map(cast));
+        Lab::from_colors_alpha(lab.map(cast), T::COMPONENT_MAX)
+    }
+}
+impl<T> Oklab<T>
+where
+    T: PixelComponent + Float,
+{
+    /// Converts from an [`Xyz`] pixel relative to the [`WhitePoint::D65`] reference white.
+    pub fn from_xyz(xyz: Xyz<T>) -> Self {
+        let oklab = linear_srgb_to_oklab(xyz_to_linear_srgb(xyz.color_array().map(cast)));
+        Self::from_colors_alpha(oklab.map(cast), T::COMPONENT_MAX)
+    }
+    /// Converts to an [`Xyz`] pixel relative to the [`WhitePoint::D65`] reference white.
+    pub fn to_xyz(self) -> Xyz<T> {
+        let xyz = linear_srgb_to_xyz(oklab_to_linear_srgb(self.color_array().map(cast)));
+        Xyz::from_colors_alpha(xyz.map(cast), T::COMPONENT_MAX)
+    }
+}
+impl<T> Oklch<T>
+where
+    T: PixelComponent + Float,
+{
+    /// Converts from the rectangular [`Oklab`] form.
+    pub fn from_oklab(oklab: Oklab<T>) -> Self {
+        let oklch = rectangular_to_polar(oklab.color_array().map(cast));
+        Self::from_colors_alpha(oklch.map(cast), T::COMPONENT_MAX)
+    }
+    /// Converts to the rectangular [`Oklab`] form.
+    pub fn to_oklab(self) -> Oklab<T> {
+        let oklab = polar_to_rectangular(self.color_array().map(cast));
+        Oklab::from_colors_alpha(oklab.map(cast), T::COMPONENT_MAX)
+    }
+}
+
+fn float_to_float<P, Q>(pixel: P) -> Q
+where
+    P: Pixel<Component: Float>,
+    Q: Pixel<Component: Float>,
+{
+    let alpha = pixel.alpha().map_or(Q::Component::COMPONENT_MAX, cast);
+    Q::from_colors_alpha(pixel.color_array().into_iter().map(cast), alpha)
+}
+
+macro_rules! perceptual_family {
+    ($from:ident, $into:ident) => {
+        impl<R, S> FromPixelCommon<$from<R>> for $into<S>
+        where
+            R: PixelComponent + Float,
+            S: PixelComponent + Float,
+        {
+            fn from_pixel_common(pixel: $from<R>) -> Self {
+                float_to_float(pixel)
+            }
+        }
+    };
+}
+
+macro_rules! rgb_perceptual {
+    ($rgb:ident, $perceptual:ident, $perceptual_alpha:ident, $from_linear:ident, $to_linear:ident) => {
+        rgb_perceptual!(@one $rgb, $perceptual, $perceptual_alpha, $from_linear, $to_linear);
+        rgb_perceptual!(@one $rgb, $perceptual_alpha, $perceptual_alpha, $from_linear, $to_linear);
+    };
+    (@one $rgb:ident, $perceptual:ident, $perceptual_alpha:ident, $from_linear:ident, $to_linear:ident) => {
+        impl<R, S> FromPixelCommon<$rgb<R>> for $perceptual<S>
+        where
+            R: PixelComponent + Enlargeable,
+            S: PixelComponent + Float,
+            f64: FromComponentCommon<R>,
+        {
+            fn from_pixel_common(pixel: $rgb<R>) -> Self {
+                let rgba = Rgba::<f64>::from_pixel_common(pixel);
+                let colors = $from_linear(rgba.color_array().map(f64::srgb_to_linear));
+
+                Self::from_pixel_common($perceptual_alpha::from_colors_alpha(colors, rgba.a))
+            }
+        }
+        impl<R, S> FromPixelCommon<$perceptual<R>> for $rgb<S>
+        where
+            R: PixelComponent + Float,
+            S: PixelComponent + FromComponentCommon<f64>,
+        {
+            fn from_pixel_common(pixel: $perceptual<R>) -> Self {
+                let perceptual = $perceptual_alpha::<f64>::from_pixel_common(pixel);
+                let colors = $to_linear(perceptual.color_array()).map(f64::linear_to_srgb);
+                let alpha = perceptual.alpha().unwrap();
+
+                Self::from_pixel_common(Rgba::from_colors_alpha(colors, alpha))
+            }
+        }
+    };
+}
+
+macro_rules! perceptual {
+    ($perceptual:ident, $perceptual_alpha:ident, $from_linear:ident, $to_linear:ident) => {
+        perceptual_family!($perceptual, $perceptual);
+        perceptual_family!($perceptual, $perceptual_alpha);
+        perceptual_family!($perceptual_alpha, $perceptual);
+        perceptual_family!($perceptual_alpha, $perceptual_alpha);
+
+        rgb_perceptual!(
+            Rgb,
+            $perceptual,
+            $perceptual_alpha,
+            $from_linear,
+            $to_linear
+        );
+        rgb_perceptual!(
+            Bgr,
+            $perceptual,
+            $perceptual_alpha,
+            $from_linear,
+            $to_linear
+        );
+        rgb_perceptual!(
+            Rgba,
+            $perceptual,
+            $perceptual_alpha,
+            $from_linear,
+            $to_linear
+        );
+        rgb_perceptual!(
+            Argb,
+            $perceptual,
+            $perceptual_alpha,
+            $from_linear,
+            $to_linear
+        );
+        rgb_perceptual!(
+            Bgra,
+            $perceptual,
+            $perceptual_alpha,
+            $from_linear,
+            $to_linear
+        );
+        rgb_perceptual!(
+            Abgr,
+            $perceptual,
+            $perceptual_alpha,
+            $from_linear,
+            $to_linear
+        );
+    };
+}
+
+perceptual!(Xyz, Xyza, linear_srgb_to_xyz, xyz_to_linear_srgb);
+perceptual!(Lab, Laba, linear_srgb_to_lab, lab_to_linear_srgb);
+perceptual!(Lch, Lcha, linear_srgb_to_lch, lch_to_linear_srgb);
+perceptual!(Oklab, Oklaba, linear_srgb_to_oklab, oklab_to_linear_srgb);
+perceptual!(Oklch, Oklcha, linear_srgb_to_oklch, oklch_to_linear_srgb);
@@ -0,0 +1,153 @@
+//! NEON implementations, which every `aarch64` CPU supports.
+//!
+//! Each function takes slices of the same number of pixels or components and returns how many
+//! destination elements it converted.
+
+use core::arch::aarch64::*;
+
+/// Calculates `(weights · pixel) / 10000` for each four `u8` component pixel in `source`.
+pub fn luma(source: &[u8], destination: &mut [u8], weights: [u16; 4]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(64)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let pixels = vld4q_u8(source.as_ptr());
+            let components = [pixels.0, pixels.1, pixels.2, pixels.3];
+
+            let mut sums = [vdupq_n_u32(0); 4];
+            for (component, weight) in components.into_iter().zip(weights) {
+                let low = vmovl_u8(vget_low_u8(component));
+                let high = vmovl_high_u8(component);
+                sums[0] = vmlal_n_u16(sums[0], vget_low_u16(low), weight);
+                sums[1] = vmlal_high_n_u16(sums[1], low, weight);
+                sums[2] = vmlal_n_u16(sums[2], vget_low_u16(high), weight);
+                sums[3] = vmlal_high_n_u16(sums[3], high, weight);
+            }
+            // The sum is exact in an `f32` and dividing is correctly rounded, so truncating the
+            // quotient matches integer division.
+            let gray =
+                sums.map(|sum| vcvtq_u32_f32(vdivq_f32(vcvtq_f32_u32(sum), vdupq_n_f32(10000.0))));
+
+            let gray = vcombine_u8(
+                vmovn_u16(vcombine_u16(vmovn_u32(gray[0]), vmovn_u32(gray[1]))),
+                vmovn_u16(vcombine_u16(vmovn_u32(gray[2]), vmovn_u32(gray[3]))),
+            );
+            vst1q_u8(destination.as_mut_ptr(), gray);
+        }
+        converted += 16;
+    }
+    converted
+}
+
+/// Swaps the components at `LOW` and `LOW + 2` in each four `u8` component pixel in `source`.
+pub fn swap<const LOW: usize>(source: &[u8], destination: &mut [u8]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(64)
+        .zip(destination.chunks_exact_mut(64))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let pixels = vld4q_u8(source.as_ptr());
+            let mut components = [pixels.0, pixels.1, pixels.2, pixels.3];
+            components.swap(LOW, LOW + 2);
+            let [c0, c1, c2, c3] = components;
+            vst4q_u8(destination.as_mut_ptr(), uint8x16x4_t(c0, c1, c2, c3));
+        }
+        converted += 64;
+    }
+    converted
+}
+
+/// Multiplies the color components of each four `u8` component pixel in `source` by the alpha
+/// component at `ALPHA`, rounding to the nearest value.
+pub fn premultiply<const ALPHA: usize>(source: &[u8], destination: &mut [u8]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(64)
+        .zip(destination.chunks_exact_mut(64))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let pixels = vld4q_u8(source.as_ptr());
+            let mut components = [pixels.0, pixels.1, pixels.2, pixels.3];
+            let alpha = components[ALPHA];
+            // Calculates `round(x / 255)` as `(x + 128 + ((x + 128) >> 8)) >> 8`, which is exact
+            // for every product of two `u8`s.
+            let multiply = |product: uint16x8_t| vraddhn_u16(product, vrshrq_n_u16(product, 8));
+            for (i, component) in components.iter_mut().enumerate() {
+                if i != ALPHA {
+                    *component = vcombine_u8(
+                        multiply(vmull_u8(vget_low_u8(*component), vget_low_u8(alpha))),
+                        multiply(vmull_high_u8(*component, alpha)),
+                    );
+                }
+            }
+            let [c0, c1, c2, c3] = components;
+            vst4q_u8(destination.as_mut_ptr(), uint8x16x4_t(c0, c1, c2, c3));
+        }
+        converted += 64;
+    }
+    converted
+}
+
+/// Calculates `x / 255` for each component in `source`.
+pub fn u8_to_f32(source: &[u8], destination: &mut [f32]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(16)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let components = vld1q_u8(source.as_ptr());
+            let low = vmovl_u8(vget_low_u8(components));
+            let high = vmovl_high_u8(components);
+            let quarters = [
+                vmovl_u16(vget_low_u16(low)),
+                vmovl_high_u16(low),
+                vmovl_u16(vget_low_u16(high)),
+                vmovl_high_u16(high),
+            ];
+            for (i, quarter) in quarters.into_iter().enumerate() {
+                let scaled = vdivq_f32(vcvtq_f32_u32(quarter), vdupq_n_f32(255.0));
+                vst1q_f32(destination.as_mut_ptr().add(i * 4), scaled);
+            }
+        }
+        converted += 16;
+    }
+    converted
+}
+
+/// Calculates `round(clamp(x, 0, 1) * 255)` for each component in `source`, rounding half away
+/// from zero.
+pub fn f32_to_u8(source: &[f32], destination: &mut [u8]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(16)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let quarters: [uint32x4_t; 4] = core::array::from_fn(|i| {
+                let components = vld1q_f32(source.as_ptr().add(i * 4));
+                // `vmaxnmq_f32()` returns the number for NaN, which rounds to zero just like the
+                // scalar conversion.
+                let clamped =
+                    vminq_f32(vmaxnmq_f32(components, vdupq_n_f32(0.0)), vdupq_n_f32(1.0));
+                vcvtaq_u32_f32(vmulq_f32(clamped, vdupq_n_f32(255.0)))
+            });
+
+            let packed = vcombine_u8(
+                vmovn_u16(vcombine_u16(vmovn_u32(quarters[0]), vmovn_u32(quarters[1]))),
+                vmovn_u16(vcombine_u16(vmovn_u32(quarters[2]), vmovn_u32(quarters[3]))),
+            );
+            vst1q_u8(destination.as_mut_ptr(), packed);
+        }
+        converted += 16;
+    }
+    converted
+}
@@ -0,0 +1,299 @@
+//! Vectorized implementations of the most common `u8` slice conversions.
+//!
+//! Each function converts as many leading elements as it can vectorize and returns how many it
+//! converted, leaving the remainder to the scalar implementation so that the results are always
+//! identical. SSE2 and NEON are part of the baseline `x86_64` and `aarch64` targets, so no runtime
+//! detection is needed, and other targets vectorize nothing.
+
+use crate::pixel::sealed::{Component, Layout};
+use crate::*;
+
+use super::to_gray::{SRGB_LUMA, SRGB_LUMA_DIV};
+
+#[cfg(target_arch = "aarch64")]
+#[path = "aarch64.rs"]
+mod arch;
+#[cfg(target_arch = "x86_64")]
+#[path = "x86_64.rs"]
+mod arch;
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod arch {
+    pub fn luma(_: &[u8], _: &mut [u8], _: [u16; 4]) -> usize {
+        0
+    }
+    pub fn swap<const LOW: usize>(_: &[u8], _: &mut [u8]) -> usize {
+        0
+    }
+    pub fn premultiply<const ALPHA: usize>(_: &[u8], _: &mut [u8]) -> usize {
+        0
+    }
+    pub fn u8_to_f32(_: &[u8], _: &mut [f32]) -> usize {
+        0
+    }
+    pub fn f32_to_u8(_: &[f32], _: &mut [u8]) -> usize {
+        0
+    }
+}
+
+/// Returns the layout of `P` if it is a `u8` pixel, or [`Layout::Other`].
+fn u8_layout<P: Pixel>() -> Layout {
+    if P::Component::sealed_component() == Component::U8 {
+        P::sealed_layout()
+    } else {
+        Layout::Other
+    }
+}
+
+/// Reinterprets a slice of `T` as a slice of `u8`s.
+///
+/// # Safety
+///
+/// `T` must be a `u8` or a `#[repr(C)]` pixel of `u8`s.
+unsafe fn bytes<T>(slice: &[T]) -> &[u8] {
+    core::slice::from_raw_parts(slice.as_ptr().cast(), core::mem::size_of_val(slice))
+}
+/// Reinterprets a mutable slice of `T` as a slice of `u8`s.
+///
+/// # Safety
+///
+/// `T` must be a `u8` or a `#[repr(C)]` pixel of `u8`s.
+unsafe fn bytes_mut<T>(slice: &mut [T]) -> &mut [u8] {
+    core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), core::mem::size_of_val(slice))
+}
+
+/// Converts four component `u8` pixels to [`Gray<u8>`].
+pub(crate) fn to_gray<P: Pixel, Q: Pixel>(source: &[P], destination: &mut [Q]) -> usize {
+    let [r, g, b] = SRGB_LUMA;
+    let weights = match u8_layout::<P>() {
+        Layout::Rgba => [r, g, b, 0],
+        Layout::Bgra => [b, g, r, 0],
+        Layout::Argb => [0, r, g, b],
+        Layout::Abgr => [0, b, g, r],
+        _ => return 0,
+    };
+    if u8_layout::<Q>() != Layout::Gray {
+        return 0;
+    }
+    debug_assert_eq!(SRGB_LUMA_DIV, 10000);
+
+    //This is safe since both pixel types were checked above.
+    unsafe { arch::luma(bytes(source), bytes_mut(destination), weights) }
+}
+
+/// Converts between four component `u8` pixels whose layouts differ by swapping the red and blue
+/// components.
+pub(crate) fn swap_red_blue<P: Pixel, Q: Pixel>(source: &[P], destination: &mut [Q]) -> usize {
+    let low = match (u8_layout::<P>(), u8_layout::<Q>()) {
+        (Layout::Rgba, Layout::Bgra) | (Layout::Bgra, Layout::Rgba) => 0,
+        (Layout::Argb, Layout::Abgr) | (Layout::Abgr, Layout::Argb) => 1,
+        _ => return 0,
+    };
+
+    //This is safe since both pixel types were checked above.
+    let (source, destination) = unsafe { (bytes(source), bytes_mut(destination)) };
+    match low {
+        0 => arch::swap::<0>(source, destination) / 4,
+        _ => arch::swap::<1>(source, destination) / 4,
+    }
+}
+
+/// Swaps the components at `low` and `low + 2` of four component `u8` pixels in place, returning
+/// how many components it swapped.
+pub(crate) fn swap_in_place<T: PixelComponent>(components: &mut [T], low: usize) -> usize {
+    if T::sealed_component() != Component::U8 {
+        return 0;
+    }
+
+    //This is safe since `T` was checked above.
+    let components = unsafe { bytes_mut(components) };
+    let mut converted = 0;
+    for chunk in components.chunks_exact_mut(64) {
+        let source: [u8; 64] = (*chunk).try_into().unwrap();
+        let swapped = match low {
+            0 => arch::swap::<0>(&source, chunk),
+            _ => arch::swap::<1>(&source, chunk),
+        };
+        if swapped != chunk.len() {
+            break;
+        }
+        converted += swapped;
+    }
+    converted
+}
+
+/// Premultiplies four component `u8` pixels.
+pub(crate) fn premultiply<P: Pixel>(source: &[P], destination: &mut [Premultiplied<P>]) -> usize {
+    let alpha = match u8_layout::<P>() {
+        Layout::Rgba | Layout::Bgra => 3,
+        Layout::Argb | Layout::Abgr => 0,
+        _ => return 0,
+    };
+
+    //This is safe since the pixel type was checked above and `Premultiplied` is
+    //#[repr(transparent)].
+    let (source, destination) = unsafe { (bytes(source), bytes_mut(destination)) };
+    match alpha {
+        3 => arch::premultiply::<3>(source, destination) / 4,
+        _ => arch::premultiply::<0>(source, destination) / 4,
+    }
+}
+
+/// Converts `u8` components to `f32` components.
+pub(crate) fn u8_to_float<F: PixelComponent>(source: &[u8], destination: &mut [F]) -> usize {
+    if F::sealed_component() != Component::F32 {
+        return 0;
+    }
+
+    //This is safe since `F` was checked above.
+    let destination = unsafe {
+        core::slice::from_raw_parts_mut(destination.as_mut_ptr().cast(), destination.len())
+    };
+    arch::u8_to_f32(source, destination)
+}
+
+/// Converts `f32` components to `u8` components.
+pub(crate) fn float_to_u8<F: PixelComponent>(source: &[F], destination: &mut [u8]) -> usize {
+    if F::sealed_component() != Component::F32 {
+        return 0;
+    }
+
+    //This is safe since `F` was checked above.
+    let source = unsafe { core::slice::from_raw_parts(source.as_ptr().cast(), source.len()) };
+    arch::f32_to_u8(source, destination)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+
+    /// Slice lengths covering empty slices, tails shorter than one chunk and several chunks with
+    /// and without a tail.
+    fn lengths(max: usize) -> impl Iterator<Item = usize> {
+        (0..=40)
+            .chain([63, 64, 65, 127, 129, 255, 257, 1023, max])
+            .filter(move |len| *len <= max)
+    }
+
+    /// Four component pixels with every pair of `u8` values in the first and last component and
+    /// every `u8` value in the others.
+    fn pixels<P: Pixel<Component = u8>>() -> Vec<P> {
+        (0..=u8::MAX)
+            .flat_map(|x| (0..=u8::MAX).map(move |y| [x, y, y.wrapping_mul(7), y ^ x]))
+            .map(|[x, y, z, w]| P::from_components([y, z, w, x]))
+            .collect()
+    }
+
+    /// Asserts that a vectorized slice conversion matches converting each element with `scalar`,
+    /// and that the vectorized path actually ran on targets that have one.
+    fn check<T: Copy, U: Copy + Default + PartialEq + core::fmt::Debug>(
+        source: &[T],
+        vectorized: impl Fn(&[T], &mut [U]) -> usize,
+        scalar: impl Fn(T) -> U,
+    ) {
+        for len in lengths(source.len()) {
+            for offset in [0, 1, 3] {
+                let source = &source[offset.min(source.len() - len)..][..len];
+                let expected = source.iter().map(|x| scalar(*x)).collect::<Vec<_>>();
+
+                let mut destination = std::vec![U::default(); len];
+                let converted = vectorized(source, &mut destination);
+                assert!(converted <= len);
+                if cfg!(any(target_arch = "x86_64", target_arch = "aarch64")) && len >= 64 {
+                    assert_ne!(converted, 0, "nothing was vectorized for length {len}");
+                }
+                for (destination, source) in destination[converted..]
+                    .iter_mut()
+                    .zip(&source[converted..])
+                {
+                    *destination = scalar(*source);
+                }
+
+                assert_eq!(destination, expected, "length {len}, offset {offset}");
+            }
+        }
+    }
+
+    fn check_pixel<P, Q>(vectorized: impl Fn(&[P], &mut [Q]) -> usize)
+    where
+        P: Pixel<Component = u8>,
+        Q: Pixel + FromPixelCommon<P> + Default + PartialEq + core::fmt::Debug,
+    {
+        check(&pixels::<P>(), vectorized, Q::from_pixel_common);
+    }
+
+    #[test]
+    fn to_gray_matches_scalar() {
+        check_pixel::<Rgba<u8>, Gray<u8>>(to_gray);
+        check_pixel::<Bgra<u8>, Gray<u8>>(to_gray);
+        check_pixel::<Argb<u8>, Gray<u8>>(to_gray);
+        check_pixel::<Abgr<u8>, Gray<u8>>(to_gray);
+    }
+
+    #[test]
+    fn swap_red_blue_matches_scalar() {
+        check_pixel::<Rgba<u8>, Bgra<u8>>(swap_red_blue);
+        check_pixel::<Bgra<u8>, Rgba<u8>>(swap_red_blue);
+        check_pixel::<Argb<u8>, Abgr<u8>>(swap_red_blue);
+        check_pixel::<Abgr<u8>, Argb<u8>>(swap_red_blue);
+    }
+
+    #[test]
+    fn swap_in_place_matches_scalar() {
+        let pixels = pixels::<Rgba<u8>>();
+        let components = Rgba::slice_to_components(&pixels);
+        for low in [0, 1] {
+            let swap = |pixel: &mut [u8]| pixel.swap(low, low + 2);
+            for len in lengths(pixels.len()) {
+                let mut expected = components[..4 * len].to_vec();
+                expected.chunks_exact_mut(4).for_each(swap);
+
+                let mut swapped = components[..4 * len].to_vec();
+                let converted = swap_in_place(&mut swapped, low);
+                assert_eq!(converted % 4, 0);
+                if cfg!(any(target_arch = "x86_64", target_arch = "aarch64")) && len >= 16 {
+                    assert_ne!(converted, 0, "nothing was vectorized for length {len}");
+                }
+                swapped[converted..].chunks_exact_mut(4).for_each(swap);
+
+                assert_eq!(swapped, expected, "length {len}, low {low}");
+            }
+        }
+    }
+
+    #[test]
+    fn premultiply_matches_scalar() {
+        fn check_premultiply<P>()
+        where
+            P: Pixel<Component = u8> + Default + PartialEq + core::fmt::Debug,
+        {
+            check(&pixels::<P>(), premultiply, Premultiplied::premultiply);
+        }
+        check_premultiply::<Rgba<u8>>();
+        check_premultiply::<Bgra<u8>>();
+        check_premultiply::<Argb<u8>>();
+        check_premultiply::<Abgr<u8>>();
+    }
+
+    #[test]
+    fn u8_to_float_matches_scalar() {
+        let source = (0..=u8::MAX).cycle().take(1024).collect::<Vec<_>>();
+        check(&source, u8_to_float, f32::from_component_common);
+    }
+
+    #[test]
+    fn float_to_u8_matches_scalar() {
+        let source = (0..=u8::MAX)
+            .flat_map(|x| {
+                let x = f32::from(x) / 255.0;
+                // Include the values halfway between codes and out of range values.
+                [x, x + 0.5 / 255.0, x - 0.5 / 255.0, x * 2.0 - 0.5]
+            })
+            .chain([f32::INFINITY, f32::NEG_INFINITY, -0.0, f32::MIN_POSITIVE])
+            .collect::<Vec<_>>();
+        check(&source, float_to_u8, u8::from_component_common);
+    }
+}
@@ -0,0 +1,184 @@
+//! SSE2 implementations, which every `x86_64` CPU supports.
+//!
+//! Each function takes slices of the same number of pixels or components and returns how many
+//! destination elements it converted.
+
+use core::arch::x86_64::*;
+
+/// Calculates `(weights · pixel) / 10000` for each four `u8` component pixel in `source`.
+pub fn luma(source: &[u8], destination: &mut [u8], weights: [u16; 4]) -> usize {
+    let [w0, w1, w2, w3] = weights.map(|weight| weight as i16);
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(64)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let weights = _mm_setr_epi16(w0, w1, w2, w3, w0, w1, w2, w3);
+            let zero = _mm_setzero_si128();
+            let divisor = _mm_set1_ps(10000.0);
+
+            let gray: [__m128i; 4] = core::array::from_fn(|i| {
+                let pixels = _mm_loadu_si128(source.as_ptr().add(i * 16).cast());
+                // Each pair of lanes holds the two halves of one pixel's weighted sum.
+                let low =
+                    _mm_castsi128_ps(_mm_madd_epi16(_mm_unpacklo_epi8(pixels, zero), weights));
+                let high =
+                    _mm_castsi128_ps(_mm_madd_epi16(_mm_unpackhi_epi8(pixels, zero), weights));
+                let sum = _mm_add_epi32(
+                    _mm_castps_si128(_mm_shuffle_ps(low, high, 0b10_00_10_00)),
+                    _mm_castps_si128(_mm_shuffle_ps(low, high, 0b11_01_11_01)),
+                );
+                // The sum is exact in an `f32` and dividing is correctly rounded, so truncating
+                // the quotient matches integer division.
+                _mm_cvttps_epi32(_mm_div_ps(_mm_cvtepi32_ps(sum), divisor))
+            });
+
+            let gray = _mm_packus_epi16(
+                _mm_packs_epi32(gray[0], gray[1]),
+                _mm_packs_epi32(gray[2], gray[3]),
+            );
+            _mm_storeu_si128(destination.as_mut_ptr().cast(), gray);
+        }
+        converted += 16;
+    }
+    converted
+}
+
+/// Swaps the components at `LOW` and `LOW + 2` in each four `u8` component pixel in `source`.
+pub fn swap<const LOW: usize>(source: &[u8], destination: &mut [u8]) -> usize {
+    let low = 0xFF_u32 << (8 * LOW);
+    let keep = !(low | low << 16);
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(16)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let low = _mm_set1_epi32(low as i32);
+            let keep = _mm_set1_epi32(keep as i32);
+
+            let pixels = _mm_loadu_si128(source.as_ptr().cast());
+            let swapped = _mm_or_si128(
+                _mm_and_si128(pixels, keep),
+                _mm_or_si128(
+                    _mm_and_si128(_mm_srli_epi32(pixels, 16), low),
+                    _mm_slli_epi32(_mm_and_si128(pixels, low), 16),
+                ),
+            );
+            _mm_storeu_si128(destination.as_mut_ptr().cast(), swapped);
+        }
+        converted += 16;
+    }
+    converted
+}
+
+/// Multiplies the color components of each four `u8` component pixel in `source` by the alpha
+/// component at `ALPHA`, rounding to the nearest value.
+pub fn premultiply<const ALPHA: usize>(source: &[u8], destination: &mut [u8]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(16)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let zero = _mm_setzero_si128();
+            let mut alpha_lanes = [0_i16; 8];
+            alpha_lanes[ALPHA] = -1;
+            alpha_lanes[ALPHA + 4] = -1;
+            let alpha_mask = _mm_loadu_si128(alpha_lanes.as_ptr().cast());
+            // Multiplying the alpha component by the maximum leaves it unchanged.
+            let alpha_max = _mm_and_si128(alpha_mask, _mm_set1_epi16(255));
+
+            let pixels = _mm_loadu_si128(source.as_ptr().cast());
+            let multiply = |pixels: __m128i| {
+                let alpha = match ALPHA {
+                    0 => _mm_shufflehi_epi16(_mm_shufflelo_epi16(pixels, 0x00), 0x00),
+                    _ => _mm_shufflehi_epi16(_mm_shufflelo_epi16(pixels, 0xFF), 0xFF),
+                };
+                let factors = _mm_or_si128(_mm_andnot_si128(alpha_mask, alpha), alpha_max);
+                // Calculates `round(x / 255)` as `(x + 128 + ((x + 128) >> 8)) >> 8`, which is
+                // exact for every product of two `u8`s.
+                let product = _mm_add_epi16(_mm_mullo_epi16(pixels, factors), _mm_set1_epi16(128));
+                _mm_srli_epi16(_mm_add_epi16(product, _mm_srli_epi16(product, 8)), 8)
+            };
+
+            let premultiplied = _mm_packus_epi16(
+                multiply(_mm_unpacklo_epi8(pixels, zero)),
+                multiply(_mm_unpackhi_epi8(pixels, zero)),
+            );
+            _mm_storeu_si128(destination.as_mut_ptr().cast(), premultiplied);
+        }
+        converted += 16;
+    }
+    converted
+}
+
+/// Calculates `x / 255` for each component in `source`.
+pub fn u8_to_f32(source: &[u8], destination: &mut [f32]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(16)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let zero = _mm_setzero_si128();
+            let divisor = _mm_set1_ps(255.0);
+
+            let components = _mm_loadu_si128(source.as_ptr().cast());
+            let low = _mm_unpacklo_epi8(components, zero);
+            let high = _mm_unpackhi_epi8(components, zero);
+            let quarters = [
+                _mm_unpacklo_epi16(low, zero),
+                _mm_unpackhi_epi16(low, zero),
+                _mm_unpacklo_epi16(high, zero),
+                _mm_unpackhi_epi16(high, zero),
+            ];
+            for (i, quarter) in quarters.into_iter().enumerate() {
+                let scaled = _mm_div_ps(_mm_cvtepi32_ps(quarter), divisor);
+                _mm_storeu_ps(destination.as_mut_ptr().add(i * 4), scaled);
+            }
+        }
+        converted += 16;
+    }
+    converted
+}
+
+/// Calculates `round(clamp(x, 0, 1) * 255)` for each component in `source`, rounding half away
+/// from zero.
+pub fn f32_to_u8(source: &[f32], destination: &mut [u8]) -> usize {
+    let mut converted = 0;
+    for (source, destination) in source
+        .chunks_exact(16)
+        .zip(destination.chunks_exact_mut(16))
+    {
+        //This is safe since each chunk is the length being loaded or stored.
+        unsafe {
+            let quarters: [__m128i; 4] = core::array::from_fn(|i| {
+                let components = _mm_loadu_ps(source.as_ptr().add(i * 4));
+                // `_mm_max_ps()` returns its second operand for NaN, which rounds to zero just
+                // like the scalar conversion.
+                let clamped =
+                    _mm_min_ps(_mm_max_ps(components, _mm_setzero_ps()), _mm_set1_ps(1.0));
+                let scaled = _mm_mul_ps(clamped, _mm_set1_ps(255.0));
+                let truncated = _mm_cvttps_epi32(scaled);
+                let fraction = _mm_sub_ps(scaled, _mm_cvtepi32_ps(truncated));
+                // The comparison mask is -1 in lanes which round up.
+                let round_up = _mm_castps_si128(_mm_cmpge_ps(fraction, _mm_set1_ps(0.5)));
+                _mm_sub_epi32(truncated, round_up)
+            });
+
+            let packed = _mm_packus_epi16(
+                _mm_packs_epi32(quarters[0], quarters[1]),
+                _mm_packs_epi32(quarters[2], quarters[3]),
+            );
+            _mm_storeu_si128(destination.as_mut_ptr().cast(), packed);
+        }
+        converted += 16;
+    }
+    converted
+}
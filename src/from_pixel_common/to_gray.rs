@@ -4,10 +4,49 @@ use crate::*;
 
 use super::enlargeable::Enlargeable;
 
-pub trait ToGray: Pixel {
+/// Coefficients to transform from sRGB to a CIE Y (luminance) value.
+pub(crate) const SRGB_LUMA: [u16; 3] = [2126, 7152, 722];
+pub(crate) const SRGB_LUMA_DIV: u16 = 10000;
+
+/// The [`SRGB_LUMA`] coefficients converted to a larger component type, so that bulk conversions
+/// only need to convert them once rather than once per pixel.
+pub struct Luma<L> {
+    r: L,
+    g: L,
+    b: L,
+    div: L,
+}
+impl<L> Luma<L>
+where
+    L: PixelComponent,
+{
+    pub fn new() -> Self {
+        Self {
+            r: NumCast::from(SRGB_LUMA[0]).unwrap(),
+            g: NumCast::from(SRGB_LUMA[1]).unwrap(),
+            b: NumCast::from(SRGB_LUMA[2]).unwrap(),
+            div: NumCast::from(SRGB_LUMA_DIV).unwrap(),
+        }
+    }
+
+    fn apply<T>(&self, r: T, g: T, b: T) -> T
+    where
+        T: Enlargeable<Larger = L>,
+    {
+        let l = self.r * r.to_larger() + self.g * g.to_larger() + self.b * b.to_larger();
+
+        T::clamp_from(l / self.div)
+    }
+}
+
+pub trait ToGray: Pixel<Component: Enlargeable> {
     type Gray: Pixel;
 
-    fn to_gray(self) -> Self::Gray;
+    fn to_gray(self) -> Self::Gray {
+        self.to_gray_with(&Luma::new())
+    }
+
+    fn to_gray_with(self, luma: &Luma<<Self::Component as Enlargeable>::Larger>) -> Self::Gray;
 }
 
 macro_rules! implement_to_gray_without_alpha {
@@ -18,17 +57,9 @@ macro_rules! implement_to_gray_without_alpha {
         {
             type Gray = Gray<T>;
 
-            fn to_gray(self) -> Self::Gray {
-                /// Coefficients to transform from sRGB to a CIE Y (luminance) value.
-                const SRGB_LUMA: [u16; 3] = [2126, 7152, 722];
-                const SRGB_LUMA_DIV: u16 = 10000;
-
-                let l = <T::Larger as NumCast>::from(SRGB_LUMA[0]).unwrap() * self.r.to_larger()
-                    + <T::Larger as NumCast>::from(SRGB_LUMA[1]).unwrap() * self.g.to_larger()
-                    + <T::Larger as NumCast>::from(SRGB_LUMA[2]).unwrap() * self.b.to_larger();
-
+            fn to_gray_with(self, luma: &Luma<T::Larger>) -> Self::Gray {
                 Gray {
-                    gray: T::clamp_from(l / <T::Larger as NumCast>::from(SRGB_LUMA_DIV).unwrap()),
+                    gray: luma.apply(self.r, self.g, self.b),
                 }
             }
         }
@@ -42,17 +73,9 @@ macro_rules! implement_to_gray_with_alpha {
         {
             type Gray = GrayAlpha<T>;
 
-            fn to_gray(self) -> Self::Gray {
-                /// Coefficients to transform from sRGB to a CIE Y (luminance) value.
-                const SRGB_LUMA: [u16; 3] = [2126, 7152, 722];
-                const SRGB_LUMA_DIV: u16 = 10000;
-
-                let l = <T::Larger as NumCast>::from(SRGB_LUMA[0]).unwrap() * self.r.to_larger()
-                    + <T::Larger as NumCast>::from(SRGB_LUMA[1]).unwrap() * self.g.to_larger()
-                    + <T::Larger as NumCast>::from(SRGB_LUMA[2]).unwrap() * self.b.to_larger();
-
+            fn to_gray_with(self, luma: &Luma<T::Larger>) -> Self::Gray {
                 GrayAlpha {
-                    gray: T::clamp_from(l / <T::Larger as NumCast>::from(SRGB_LUMA_DIV).unwrap()),
+                    gray: luma.apply(self.r, self.g, self.b),
                     a: self.a,
                 }
             }
@@ -0,0 +1,216 @@
+use num_traits::NumCast;
+
+use crate::*;
+
+/// The matrix coefficients used to convert between gamma-encoded RGB and [`YCbCr`].
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let red = Rgb::<u8> { r: 255, g: 0, b: 0 };
+/// let y_cb_cr = YCbCr::<u8>::from_rgb(red, YCbCrMatrix::Bt601, YCbCrRange::Limited);
+///
+/// assert_eq!(y_cb_cr, YCbCr { y: 81, cb: 90, cr: 240 });
+///
+/// let y_cb_cr = YCbCr::<u16>::from_rgb(red, YCbCrMatrix::Bt709, YCbCrRange::Limited);
+/// assert_eq!(y_cb_cr.to_rgb::<Rgb<u8>>(YCbCrMatrix::Bt709, YCbCrRange::Limited), red);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum YCbCrMatrix {
+    /// ITU-R BT.601, used by standard definition video and JPEG.
+    Bt601,
+    /// ITU-R BT.709, used by high definition video.
+    Bt709,
+    /// ITU-R BT.2020, used by ultra high definition video.
+    Bt2020,
+}
+impl YCbCrMatrix {
+    /// Returns the red and blue luma coefficients.
+    fn kr_kb(self) -> (f64, f64) {
+        match self {
+            YCbCrMatrix::Bt601 => (0.299, 0.114),
+            YCbCrMatrix::Bt709 => (0.2126, 0.0722),
+            YCbCrMatrix::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+
+    /// Converts normalized RGB to luma in `0.0..=1.0` and chroma in `-0.5..=0.5`.
+    fn rgb_to_y_cb_cr(self, [r, g, b]: [f64; 3]) -> [f64; 3] {
+        let (kr, kb) = self.kr_kb();
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+
+        [
+            y,
+            (b - y) / (2.0 * (1.0 - kb)),
+            (r - y) / (2.0 * (1.0 - kr)),
+        ]
+    }
+    fn y_cb_cr_to_rgb(self, [y, cb, cr]: [f64; 3]) -> [f64; 3] {
+        let (kr, kb) = self.kr_kb();
+        let r = y + 2.0 * (1.0 - kr) * cr;
+        let b = y + 2.0 * (1.0 - kb) * cb;
+
+        [r, (y - kr * r - kb * b) / (1.0 - kr - kb), b]
+    }
+}
+
+/// The range of component values used by [`YCbCr`] pixels.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum YCbCrRange {
+    /// Luma and chroma use the whole component range, as in JPEG.
+    Full,
+    /// Luma uses `16..=235` and chroma uses `16..=240` scaled to the component's bit depth, as in
+    /// most video. Also known as studio swing or TV range.
+    ///
+    /// Float components use the 8-bit values divided by 255.
+    Limited,
+}
+
+/// How normalized values map to the codes of a component type.
+struct Quantization<T> {
+    integer: bool,
+    /// The largest code, which is `COMPONENT_MAX - COMPONENT_MIN`.
+    max: f64,
+    /// The size of one 8-bit step in codes.
+    step: f64,
+    /// The code of zero chroma.
+    middle: f64,
+    min: T,
+}
+impl<T> Quantization<T>
+where
+    T: PixelComponent,
+{
+    fn new() -> Self {
+        let integer = T::one() / (T::one() + T::one()) == T::zero();
+        let max = T::COMPONENT_MAX.to_f64().unwrap() - T::COMPONENT_MIN.to_f64().unwrap();
+
+        let (step, middle) = if integer {
+            ((max + 1.0) / 256.0, (max + 1.0) / 2.0)
+        } else {
+            (1.0 / 255.0, 0.5)
+        };
+
+        Self {
+            integer,
+            max,
+            step,
+            middle,
+            min: T::COMPONENT_MIN,
+        }
+    }
+
+    fn encode(&self, luma: bool, value: f64, range: YCbCrRange) -> f64 {
+        match (range, luma) {
+            (YCbCrRange::Full, true) => value * self.max,
+            (YCbCrRange::Full, false) => value * self.max + self.middle,
+            (YCbCrRange::Limited, true) => (16.0 + 219.0 * value) * self.step,
+            (YCbCrRange::Limited, false) => (128.0 + 224.0 * value) * self.step,
+        }
+    }
+    fn decode(&self, luma: bool, code: f64, range: YCbCrRange) -> f64 {
+        match (range, luma) {
+            (YCbCrRange::Full, true) => code / self.max,
+            (YCbCrRange::Full, false) => (code - self.middle) / self.max,
+            (YCbCrRange::Limited, true) => (code / self.step - 16.0) / 219.0,
+            (YCbCrRange::Limited, false) => (code / self.step - 128.0) / 224.0,
+        }
+    }
+
+    fn quantize(&self, code: f64) -> T {
+        if self.integer {
+            let code = libm::round(code).clamp(0.0, self.max);
+            <T as NumCast>::from(code + self.min.to_f64().unwrap()).unwrap()
+        } else {
+            <T as NumCast>::from(code).unwrap()
+        }
+    }
+    fn code(&self, component: T) -> f64 {
+        // Subtracting in `T` would overflow for signed components.
+        component.to_f64().unwrap() - self.min.to_f64().unwrap()
+    }
+}
+
+macro_rules! implement_y_cb_cr {
+    ($name:ident) => {
+        impl<T> $name<T>
+        where
+            T: PixelComponent,
+        {
+            /// Converts from a gamma-encoded RGB family pixel using the given matrix
+            /// coefficients and range.
+            ///
+            /// Integer components are rounded to the nearest code. Alpha always uses the whole
+            /// component range.
+            ///
+            /// # Examples
+            /// ```
+            /// use pixeli::*;
+            ///
+            /// for a in [0, 100, 255] {
+            ///     let rgba = Rgba::<u8> { r: 128, g: 128, b: 128, a };
+            ///     let y_cb_cr_a =
+            ///         YCbCrA::<u8>::from_rgb(rgba, YCbCrMatrix::Bt601, YCbCrRange::Limited);
+            ///
+            ///     assert_eq!(y_cb_cr_a.a, a);
+            ///     assert_eq!(
+            ///         y_cb_cr_a.to_rgb::<Rgba<u8>>(YCbCrMatrix::Bt601, YCbCrRange::Limited),
+            ///         rgba
+            ///     );
+            /// }
+            ///
+            /// // Signed components are offset by their minimum value.
+            /// let black = Rgb::<u8> { r: 0, g: 0, b: 0 };
+            /// let y_cb_cr = YCbCr::<i8>::from_rgb(black, YCbCrMatrix::Bt709, YCbCrRange::Full);
+            /// assert_eq!(y_cb_cr, YCbCr { y: -128, cb: 0, cr: 0 });
+            ///
+            /// let y_cb_cr = YCbCr::<i8> { y: 0, cb: 0, cr: 0 };
+            /// assert_eq!(
+            ///     y_cb_cr.to_rgb::<Rgb<u8>>(YCbCrMatrix::Bt709, YCbCrRange::Full),
+            ///     Rgb { r: 128, g: 128, b: 128 }
+            /// );
+            /// ```
+            pub fn from_rgb<P>(pixel: P, matrix: YCbCrMatrix, range: YCbCrRange) -> Self
+            where
+                Rgba<f64>: FromPixelCommon<P>,
+            {
+                let rgba = Rgba::<f64>::from_pixel_common(pixel);
+                let [y, cb, cr] = matrix.rgb_to_y_cb_cr(rgba.color_array());
+
+                let quantization = Quantization::new();
+                let code =
+                    |luma, value| quantization.quantize(quantization.encode(luma, value, range));
+
+                Self::from_colors_alpha(
+                    [code(true, y), code(false, cb), code(false, cr)],
+                    quantization.quantize(rgba.a * quantization.max),
+                )
+            }
+
+            /// Converts to a gamma-encoded RGB family pixel using the given matrix
+            /// coefficients and range.
+            pub fn to_rgb<P>(self, matrix: YCbCrMatrix, range: YCbCrRange) -> P
+            where
+                P: FromPixelCommon<Rgba<f64>>,
+            {
+                let quantization = Quantization::new();
+                let value = |luma, component| {
+                    quantization.decode(luma, quantization.code(component), range)
+                };
+
+                let [y, cb, cr] = self.color_array();
+                let rgb =
+                    matrix.y_cb_cr_to_rgb([value(true, y), value(false, cb), value(false, cr)]);
+                let alpha = self
+                    .alpha()
+                    .map_or(1.0, |alpha| quantization.code(alpha) / quantization.max);
+
+                P::from_pixel_common(Rgba::from_colors_alpha(rgb, alpha))
+            }
+        }
+    };
+}
+
+implement_y_cb_cr!(YCbCr);
+implement_y_cb_cr!(YCbCrA);
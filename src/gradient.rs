@@ -0,0 +1,163 @@
+use alloc::vec::Vec;
+
+use crate::*;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "GradientData<P>"))]
+#[derive(Clone, Debug, PartialEq)]
+/// A gradient between colors at increasing positions, called stops.
+///
+/// Sampling between two stops interpolates them using the gradient's [`Interpolation`], while
+/// sampling before the first stop or after the last stop gives the color of that stop. Two stops
+/// at the same position make a hard edge.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let gradient = Gradient::new([
+///     (0.0, Rgb::<u8> { r: 255, g: 0, b: 0 }),
+///     (0.5, Rgb::<u8> { r: 0, g: 255, b: 0 }),
+///     (1.0, Rgb::<u8> { r: 0, g: 0, b: 255 }),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(gradient.sample(0.25), Rgb { r: 128, g: 128, b: 0 });
+/// assert_eq!(gradient.sample(2.0), Rgb { r: 0, g: 0, b: 255 });
+///
+/// let ramp: Vec<_> = gradient.samples(5).collect();
+/// assert_eq!(ramp[1], gradient.sample(0.25));
+///
+/// let mut row = [Rgb::default(); 5];
+/// gradient.fill(&mut row);
+/// assert_eq!(row.as_slice(), ramp);
+/// ```
+///
+/// Deserializing checks and sorts the stops in the same way as [`Gradient::new()`].
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use pixeli::*;
+///
+/// let json = r#"{"stops":[],"interpolation":"Straight"}"#;
+/// assert!(serde_json::from_str::<Gradient<Gray<u8>>>(json).is_err());
+///
+/// let json = r#"{"stops":[[1.0,{"gray":255}],[0.0,{"gray":0}]],"interpolation":"Straight"}"#;
+/// let gradient = serde_json::from_str::<Gradient<Gray<u8>>>(json).unwrap();
+/// assert_eq!(gradient.sample(0.5), Gray { gray: 128 });
+/// # }
+/// ```
+pub struct Gradient<P> {
+    stops: Vec<(f64, P)>,
+    interpolation: Interpolation,
+}
+
+/// The fields of a [`Gradient`] before its stops are checked when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GradientData<P> {
+    stops: Vec<(f64, P)>,
+    interpolation: Interpolation,
+}
+#[cfg(feature = "serde")]
+impl<P> TryFrom<GradientData<P>> for Gradient<P> {
+    type Error = &'static str;
+
+    fn try_from(data: GradientData<P>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stops: sort_stops(data.stops)
+                .ok_or("gradient stops are empty or have a NaN position")?,
+            interpolation: data.interpolation,
+        })
+    }
+}
+
+/// Sorts `stops` by position, or returns [`None`] if there are no stops or any position is NaN.
+fn sort_stops<P>(mut stops: Vec<(f64, P)>) -> Option<Vec<(f64, P)>> {
+    if stops.is_empty() || stops.iter().any(|(position, _)| position.is_nan()) {
+        return None;
+    }
+    stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    Some(stops)
+}
+
+impl<P> Gradient<P>
+where
+    P: Pixel,
+    P::Component: FromComponentCommon<f64>,
+    f64: FromComponentCommon<P::Component>,
+{
+    /// Creates a gradient from stops of positions and colors, which are sorted by position.
+    ///
+    /// Returns [`None`] if there are no stops or any position is NaN.
+    pub fn new(stops: impl IntoIterator<Item = (f64, P)>) -> Option<Self> {
+        Some(Self {
+            stops: sort_stops(stops.into_iter().collect())?,
+            interpolation: Interpolation::default(),
+        })
+    }
+    /// Creates a gradient with the colors evenly spaced from `0.0` to `1.0`.
+    ///
+    /// Returns [`None`] if there are no colors.
+    pub fn evenly_spaced(colors: impl IntoIterator<Item = P>) -> Option<Self> {
+        let colors: Vec<_> = colors.into_iter().collect();
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| (i as f64 / last, color)),
+        )
+    }
+    /// Sets how colors between stops are interpolated.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Returns the stops sorted by position.
+    pub fn stops(&self) -> &[(f64, P)] {
+        &self.stops
+    }
+    /// Returns how colors between stops are interpolated.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the color of the gradient at `position`.
+    pub fn sample(&self, position: f64) -> P {
+        // The index of the first stop after `position`.
+        let next = self.stops.partition_point(|(stop, _)| *stop <= position);
+        if next == 0 {
+            return self.stops[0].1;
+        }
+
+        let (start, start_color) = self.stops[next - 1];
+        match self.stops.get(next) {
+            Some(&(end, end_color)) => {
+                let t = (position - start) / (end - start);
+                self.interpolation.lerp(start_color, end_color, t)
+            }
+            None => start_color,
+        }
+    }
+    /// Returns `count` colors sampled at evenly spaced positions from the first stop to the last
+    /// stop.
+    pub fn samples(&self, count: usize) -> impl Iterator<Item = P> + '_ {
+        let start = self.stops[0].0;
+        let end = self.stops[self.stops.len() - 1].0;
+        let last = count.saturating_sub(1).max(1) as f64;
+        (0..count).map(move |i| {
+            let t = i as f64 / last;
+            self.sample(start * (1.0 - t) + end * t)
+        })
+    }
+    /// Fills `destination` with colors sampled at evenly spaced positions from the first stop to
+    /// the last stop, as in [`Gradient::samples()`].
+    pub fn fill(&self, destination: &mut [P]) {
+        let samples = self.samples(destination.len());
+        for (destination, color) in destination.iter_mut().zip(samples) {
+            *destination = color;
+        }
+    }
+}
@@ -0,0 +1,12 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An `HSL` pixel.
+pub struct Hsl<T> {
+    /// Hue Component, with one full turn spanning the component range
+    pub h: T,
+    /// Saturation Component
+    pub s: T,
+    /// Lightness Component
+    pub l: T,
+}
@@ -0,0 +1,14 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An `HSLA` pixel.
+pub struct Hsla<T> {
+    /// Hue Component, with one full turn spanning the component range
+    pub h: T,
+    /// Saturation Component
+    pub s: T,
+    /// Lightness Component
+    pub l: T,
+    /// Alpha Component
+    pub a: T,
+}
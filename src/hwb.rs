@@ -0,0 +1,12 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An `HWB` pixel.
+pub struct Hwb<T> {
+    /// Hue Component, with one full turn spanning the component range
+    pub h: T,
+    /// Whiteness Component
+    pub w: T,
+    /// Blackness Component
+    pub b: T,
+}
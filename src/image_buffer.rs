@@ -0,0 +1,298 @@
+use alloc::vec::Vec;
+
+use crate::*;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "ImageBufferData<P>",
+        bound(deserialize = "P: Pixel + serde::Deserialize<'de>")
+    )
+)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+/// An owned image of `width` by `height` pixels, with each row `stride` pixels apart.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let mut image = ImageBuffer::new(3, 2);
+/// *image.get_mut(1, 1).unwrap() = Rgb::<u8> { r: 255, g: 0, b: 0 };
+///
+/// let gray = image.convert::<Gray<u8>>();
+/// assert_eq!(gray.get(1, 1), Some(&Gray { gray: 54 }));
+/// assert_eq!(gray.rows().count(), 2);
+/// ```
+///
+/// Deserializing checks the layout in the same way as [`ImageBuffer::from_vec_with_stride()`].
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use pixeli::*;
+///
+/// let json = r#"{"data":[],"width":4,"height":4,"stride":4}"#;
+/// let error = serde_json::from_str::<ImageBuffer<Gray<u8>>>(json).unwrap_err();
+/// assert!(error.to_string().contains("buffer of length 0"));
+///
+/// let image = ImageBuffer::from_pixel(2, 1, Gray { gray: 7_u8 });
+/// let json = serde_json::to_string(&image).unwrap();
+/// assert_eq!(serde_json::from_str::<ImageBuffer<Gray<u8>>>(&json).unwrap(), image);
+/// # }
+/// ```
+pub struct ImageBuffer<P> {
+    data: Vec<P>,
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+/// The fields of an [`ImageBuffer`] before their layout is checked when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ImageBufferData<P> {
+    data: Vec<P>,
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+#[cfg(feature = "serde")]
+impl<P> TryFrom<ImageBufferData<P>> for ImageBuffer<P>
+where
+    P: Pixel,
+{
+    type Error = LayoutError;
+
+    fn try_from(data: ImageBufferData<P>) -> Result<Self, Self::Error> {
+        ImageBuffer::from_vec_with_stride(data.width, data.height, data.stride, data.data)
+    }
+}
+
+impl<P> ImageBuffer<P>
+where
+    P: Pixel,
+{
+    /// Creates an image filled with the default pixel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of pixels overflows a `usize`.
+    pub fn new(width: usize, height: usize) -> Self
+    where
+        P: Default,
+    {
+        Self::from_pixel(width, height, P::default())
+    }
+    /// Creates an image filled with `pixel`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of pixels overflows a `usize`.
+    pub fn from_pixel(width: usize, height: usize, pixel: P) -> Self {
+        Self {
+            data: alloc::vec![pixel; pixel_count(width, height)],
+            width,
+            height,
+            stride: width,
+        }
+    }
+    /// Creates an image by calling `f` with the column and row of each pixel.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> P) -> Self {
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self {
+            data,
+            width,
+            height,
+            stride: width,
+        }
+    }
+    /// Creates an image from tightly packed rows of pixels.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LayoutError`] if `data` is too small for the image.
+    pub fn from_vec(width: usize, height: usize, data: Vec<P>) -> Result<Self, LayoutError> {
+        Self::from_vec_with_stride(width, height, width, data)
+    }
+    /// Creates an image from rows of pixels which are `stride` pixels apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LayoutError`] if `stride` is smaller than `width` or `data` is too small for
+    /// the image.
+    pub fn from_vec_with_stride(
+        width: usize,
+        height: usize,
+        stride: usize,
+        data: Vec<P>,
+    ) -> Result<Self, LayoutError> {
+        LayoutError::check(data.len(), stride, width, height)?;
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+        })
+    }
+    #[cfg(feature = "libm")]
+    pub(crate) fn from_rows<'a, R>(
+        width: usize,
+        height: usize,
+        rows: impl Iterator<Item = &'a [R]>,
+    ) -> Self
+    where
+        R: Copy + 'a,
+        P: FromPixelCommon<R>,
+    {
+        use num_traits::Zero;
+
+        let blank = P::from_components(core::iter::repeat(P::Component::zero()));
+        let mut data = alloc::vec![blank; pixel_count(width, height)];
+        if width > 0 {
+            for (source, destination) in rows.zip(data.chunks_exact_mut(width)) {
+                P::from_pixel_common_slice(source, destination).unwrap();
+            }
+        }
+        Self {
+            data,
+            width,
+            height,
+            stride: width,
+        }
+    }
+
+    /// Returns the width of the image in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// Returns the height of the image in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Returns the distance between the start of each row in pixels.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns the underlying pixels, including any padding between rows.
+    pub fn as_slice(&self) -> &[P] {
+        &self.data
+    }
+    /// Returns the underlying pixels mutably, including any padding between rows.
+    pub fn as_mut_slice(&mut self) -> &mut [P] {
+        &mut self.data
+    }
+    /// Returns the underlying vector of pixels, including any padding between rows.
+    pub fn into_vec(self) -> Vec<P> {
+        self.data
+    }
+
+    /// Returns the pixel at column `x` and row `y`, or `None` if it is outside the image.
+    pub fn get(&self, x: usize, y: usize) -> Option<&P> {
+        self.row(y)?.get(x)
+    }
+    /// Returns the pixel at column `x` and row `y` mutably, or `None` if it is outside the
+    /// image.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut P> {
+        self.row_mut(y)?.get_mut(x)
+    }
+    /// Returns row `y`, or `None` if it is outside the image.
+    pub fn row(&self, y: usize) -> Option<&[P]> {
+        if y >= self.height {
+            return None;
+        }
+
+        let start = y * self.stride;
+        Some(&self.data[start..start + self.width])
+    }
+    /// Returns row `y` mutably, or `None` if it is outside the image.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [P]> {
+        if y >= self.height {
+            return None;
+        }
+
+        let start = y * self.stride;
+        Some(&mut self.data[start..start + self.width])
+    }
+    /// Returns an iterator over the rows of the image.
+    pub fn rows(&self) -> impl Iterator<Item = &[P]> {
+        (0..self.height).map(|y| self.row(y).unwrap())
+    }
+    /// Returns an iterator over the rows of the image, mutably.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [P]> {
+        let (height, stride, width) = (self.height, self.stride, self.width);
+        let mut data = &mut *self.data;
+        (0..height).map(move |y| {
+            let rest = core::mem::take(&mut data);
+            let (row, rest) = rest.split_at_mut(if y + 1 < height { stride } else { rest.len() });
+            data = rest;
+            &mut row[..width]
+        })
+    }
+
+    /// Converts each pixel of the image into a new image using [`FromPixelCommon`].
+    #[cfg(feature = "libm")]
+    pub fn convert<Q>(&self) -> ImageBuffer<Q>
+    where
+        Q: Pixel + FromPixelCommon<P>,
+    {
+        ImageBuffer::from_rows(self.width, self.height, self.rows())
+    }
+}
+
+impl<P> ImageBuffer<P>
+where
+    P: ContiguousPixel,
+{
+    /// Returns a view of the whole image.
+    pub fn view(&self) -> ImageView<'_, P> {
+        let count = usize::from(P::COMPONENT_COUNT);
+        ImageView::new_unchecked(
+            P::slice_to_components(&self.data),
+            self.width,
+            self.height,
+            self.stride * count,
+        )
+    }
+    /// Returns a mutable view of the whole image.
+    pub fn view_mut(&mut self) -> ImageViewMut<'_, P> {
+        let count = usize::from(P::COMPONENT_COUNT);
+        ImageViewMut::new_unchecked(
+            P::slice_to_components_mut(&mut self.data),
+            self.width,
+            self.height,
+            self.stride * count,
+        )
+    }
+    /// Returns a view of the rectangle at column `x` and row `y` with the given size, or `None`
+    /// if it does not fit in the image.
+    pub fn sub_image(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<ImageView<'_, P>> {
+        self.view().sub_image(x, y, width, height)
+    }
+    /// Returns a mutable view of the rectangle at column `x` and row `y` with the given size, or
+    /// `None` if it does not fit in the image.
+    pub fn sub_image_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<ImageViewMut<'_, P>> {
+        self.view_mut().into_sub_image(x, y, width, height)
+    }
+}
+
+fn pixel_count(width: usize, height: usize) -> usize {
+    width
+        .checked_mul(height)
+        .expect("image size overflows usize")
+}
@@ -0,0 +1,460 @@
+use core::fmt;
+
+use crate::pixel::pixel_component::{components_from_bytes, components_from_bytes_mut};
+use crate::*;
+
+/// A view of a rectangle of pixels stored as components, with each row `stride` components
+/// apart.
+///
+/// The stride can include padding at the end of each row, as is common for frames from GPUs and
+/// capture cards, and need not be a multiple of the number of components in a pixel.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// // Two rows of two `Rgb` pixels, padded to eight components per row.
+/// let data = [
+///     1_u8, 2, 3, 4, 5, 6, 0, 0, //
+///     7, 8, 9, 10, 11, 12,
+/// ];
+/// let view = ImageView::<Rgb<u8>>::from_components(&data, 2, 2, 8).unwrap();
+///
+/// assert_eq!(view.get(0, 1), Some(&Rgb { r: 7, g: 8, b: 9 }));
+/// assert_eq!(
+///     view.rows().collect::<Vec<_>>(),
+///     [[Rgb { r: 1, g: 2, b: 3 }, Rgb { r: 4, g: 5, b: 6 }], [Rgb { r: 7, g: 8, b: 9 }, Rgb { r: 10, g: 11, b: 12 }]]
+/// );
+///
+/// let cropped = view.sub_image(1, 0, 1, 2).unwrap();
+/// assert_eq!(cropped.get(0, 1), Some(&Rgb { r: 10, g: 11, b: 12 }));
+///
+/// // Rows too long to address are rejected rather than wrapping around.
+/// assert!(ImageView::<Rgb<u8>>::from_components(&data, usize::MAX / 3 + 1, 1, 2).is_err());
+///
+/// // The same rows given as bytes, with the stride in bytes.
+/// let view = ImageView::<Rgb<u8>>::from_bytes(&data, 2, 2, 8).unwrap();
+/// assert_eq!(view.get(1, 1), Some(&Rgb { r: 10, g: 11, b: 12 }));
+/// ```
+pub struct ImageView<'a, P>
+where
+    P: ContiguousPixel,
+{
+    data: &'a [P::Component],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+/// A mutable view of a rectangle of pixels stored as components, with each row `stride`
+/// components apart.
+///
+/// The view can be split into disjoint bands of rows to process them in parallel.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let mut data = [0_u8; 4 * 6];
+/// let mut view = ImageViewMut::<Gray<u8>>::from_components(&mut data, 3, 6, 4).unwrap();
+///
+/// std::thread::scope(|scope| {
+///     for (i, mut band) in view.row_bands_mut(2).enumerate() {
+///         scope.spawn(move || {
+///             for row in band.rows_mut() {
+///                 row.fill(Gray { gray: i as u8 + 1 });
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(view.get(2, 5), Some(&Gray { gray: 3 }));
+/// assert_eq!(&data[..8], [1, 1, 1, 0, 1, 1, 1, 0]);
+/// ```
+pub struct ImageViewMut<'a, P>
+where
+    P: ContiguousPixel,
+{
+    data: &'a mut [P::Component],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+/// Returns the length of a row in components, which must already have been checked not to
+/// overflow.
+fn row_len<P>(width: usize) -> usize
+where
+    P: Pixel,
+{
+    width * usize::from(P::COMPONENT_COUNT)
+}
+
+/// Returns the range of components covered by the rectangle at column `x` and row `y` with the
+/// given size, or `None` if it does not fit in a `width` by `height` image.
+#[allow(clippy::too_many_arguments)]
+fn sub_image_range<P>(
+    width: usize,
+    height: usize,
+    stride: usize,
+    x: usize,
+    y: usize,
+    sub_width: usize,
+    sub_height: usize,
+) -> Option<core::ops::Range<usize>>
+where
+    P: Pixel,
+{
+    if x.checked_add(sub_width)? > width || y.checked_add(sub_height)? > height {
+        return None;
+    }
+
+    let start = y * stride + row_len::<P>(x);
+    let len = match sub_height {
+        0 => 0,
+        sub_height => (sub_height - 1) * stride + row_len::<P>(sub_width),
+    };
+    Some(start..start + len)
+}
+
+macro_rules! implement_image_view {
+    (@cast $data:expr) => {
+        components_from_bytes(&$data)
+    };
+    (@cast mut $data:expr) => {
+        components_from_bytes_mut(&mut $data)
+    };
+    ($view:ident, $($mut:ident)?) => {
+        impl<'a, P> $view<'a, P>
+        where
+            P: ContiguousPixel,
+        {
+            /// Creates a view of `height` rows of `width` pixels, with each row `stride`
+            /// components apart in `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`LayoutError`] if `stride` is smaller than a row or `data` is too small
+            /// for the view.
+            pub fn from_components(
+                data: &'a $($mut)? [P::Component],
+                width: usize,
+                height: usize,
+                stride: usize,
+            ) -> Result<Self, LayoutError> {
+                let count = usize::from(P::COMPONENT_COUNT);
+                let row_len = LayoutError::checked_mul(count, width, data.len())?;
+                LayoutError::check(data.len(), stride, row_len, height)?;
+                Ok(Self::new_unchecked(data, width, height, stride))
+            }
+            /// Creates a view of `height` rows of `width` pixels, with each row `stride` bytes
+            /// apart in `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`LayoutError`] if `data` is not aligned for the components, `stride` is
+            /// not a multiple of the size of a component or is smaller than a row, or `data` is
+            /// too small for the view.
+            pub fn from_bytes(
+                data: &'a $($mut)? [u8],
+                width: usize,
+                height: usize,
+                stride: usize,
+            ) -> Result<Self, LayoutError>
+            where
+                P::Component: PodComponent,
+            {
+                let size = core::mem::size_of::<P::Component>();
+                if stride % size != 0 {
+                    return Err(CastError::SizeMismatch.into());
+                }
+
+                let len = data.len() - data.len() % size;
+                let components = implement_image_view!(@cast $($mut)? data[..len]);
+                Self::from_components(components?, width, height, stride / size)
+            }
+
+            /// Creates a view of `data`, which must already have been checked to hold the image.
+            pub(crate) fn new_unchecked(
+                data: &'a $($mut)? [P::Component],
+                width: usize,
+                height: usize,
+                stride: usize,
+            ) -> Self {
+                let end = match height {
+                    0 => 0,
+                    height => (height - 1) * stride + row_len::<P>(width),
+                };
+                Self {
+                    data: &$($mut)? data[..end],
+                    width,
+                    height,
+                    stride,
+                }
+            }
+
+            /// Returns the width of the view in pixels.
+            pub fn width(&self) -> usize {
+                self.width
+            }
+            /// Returns the height of the view in pixels.
+            pub fn height(&self) -> usize {
+                self.height
+            }
+            /// Returns the distance between the start of each row in components.
+            pub fn stride(&self) -> usize {
+                self.stride
+            }
+
+            /// Converts each pixel of the view into a new [`ImageBuffer`] using
+            /// [`FromPixelCommon`].
+            #[cfg(all(feature = "alloc", feature = "libm"))]
+            pub fn convert<Q>(&self) -> ImageBuffer<Q>
+            where
+                Q: Pixel + FromPixelCommon<P>,
+            {
+                ImageBuffer::from_rows(self.width, self.height, self.rows())
+            }
+        }
+        impl<P> fmt::Debug for $view<'_, P>
+        where
+            P: ContiguousPixel,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($view))
+                    .field("width", &self.width)
+                    .field("height", &self.height)
+                    .field("stride", &self.stride)
+                    .finish_non_exhaustive()
+            }
+        }
+    };
+}
+implement_image_view!(ImageView,);
+implement_image_view!(ImageViewMut, mut);
+
+impl<P> Clone for ImageView<'_, P>
+where
+    P: ContiguousPixel,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<P> Copy for ImageView<'_, P> where P: ContiguousPixel {}
+
+impl<'a, P> ImageView<'a, P>
+where
+    P: ContiguousPixel,
+{
+    /// Returns the pixel at column `x` and row `y`, or `None` if it is outside the view.
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a P> {
+        self.row(y)?.get(x)
+    }
+    /// Returns row `y`, or `None` if it is outside the view.
+    pub fn row(&self, y: usize) -> Option<&'a [P]> {
+        if y >= self.height {
+            return None;
+        }
+
+        let start = y * self.stride;
+        let row = &self.data[start..start + row_len::<P>(self.width)];
+        Some(P::slice_from_components(row).unwrap())
+    }
+    /// Returns an iterator over the rows of the view.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [P]>
+    where
+        P: 'a,
+    {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y).unwrap())
+    }
+
+    /// Returns a view of the rectangle at column `x` and row `y` with the given size, or `None`
+    /// if it does not fit in this view.
+    pub fn sub_image(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        let range =
+            sub_image_range::<P>(self.width, self.height, self.stride, x, y, width, height)?;
+        Some(Self {
+            data: &self.data[range],
+            width,
+            height,
+            stride: self.stride,
+        })
+    }
+}
+
+impl<'a, P> ImageView<'a, P>
+where
+    P: ContiguousPixel,
+{
+    /// Splits the view into the rows above `y` and the rows from `y` onwards, or returns `None`
+    /// if `y` is greater than the height.
+    pub fn split_at_row(&self, y: usize) -> Option<(Self, Self)> {
+        if y > self.height {
+            return None;
+        }
+
+        let (top, bottom) = self.data.split_at((y * self.stride).min(self.data.len()));
+        Some((
+            Self::new_unchecked(top, self.width, y, self.stride),
+            Self::new_unchecked(bottom, self.width, self.height - y, self.stride),
+        ))
+    }
+    /// Returns an iterator over bands of `band_height` rows, with the last band containing any
+    /// remaining rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `band_height` is zero.
+    pub fn row_bands(&self, band_height: usize) -> impl Iterator<Item = Self>
+    where
+        P: 'a,
+    {
+        assert!(band_height > 0, "band height must not be zero");
+
+        let mut rest = *self;
+        core::iter::from_fn(move || {
+            if rest.height == 0 {
+                return None;
+            }
+
+            let (band, bottom) = rest.split_at_row(band_height.min(rest.height)).unwrap();
+            rest = bottom;
+            Some(band)
+        })
+    }
+}
+
+impl<'a, P> ImageViewMut<'a, P>
+where
+    P: ContiguousPixel,
+{
+    /// Splits the view into the rows above `y` and the rows from `y` onwards, or returns `None`
+    /// if `y` is greater than the height.
+    pub fn split_at_row(self, y: usize) -> Option<(Self, Self)> {
+        if y > self.height {
+            return None;
+        }
+
+        let mid = (y * self.stride).min(self.data.len());
+        let (top, bottom) = self.data.split_at_mut(mid);
+        Some((
+            Self::new_unchecked(top, self.width, y, self.stride),
+            Self::new_unchecked(bottom, self.width, self.height - y, self.stride),
+        ))
+    }
+    /// Returns an iterator over disjoint mutable bands of `band_height` rows, with the last band
+    /// containing any remaining rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `band_height` is zero.
+    pub fn row_bands_mut(
+        &mut self,
+        band_height: usize,
+    ) -> impl Iterator<Item = ImageViewMut<'_, P>> {
+        assert!(band_height > 0, "band height must not be zero");
+
+        let mut rest = Some(self.reborrow());
+        core::iter::from_fn(move || {
+            let view = rest.take().filter(|view| view.height > 0)?;
+            let band_height = band_height.min(view.height);
+            let (band, bottom) = view.split_at_row(band_height).unwrap();
+            rest = Some(bottom);
+            Some(band)
+        })
+    }
+
+    /// Returns the pixel at column `x` and row `y`, or `None` if it is outside the view.
+    pub fn get(&self, x: usize, y: usize) -> Option<&P> {
+        self.as_view().get(x, y)
+    }
+    /// Returns the pixel at column `x` and row `y` mutably, or `None` if it is outside the view.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut P> {
+        self.row_mut(y)?.get_mut(x)
+    }
+    /// Returns row `y`, or `None` if it is outside the view.
+    pub fn row(&self, y: usize) -> Option<&[P]> {
+        self.as_view().row(y)
+    }
+    /// Returns row `y` mutably, or `None` if it is outside the view.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [P]> {
+        if y >= self.height {
+            return None;
+        }
+
+        let start = y * self.stride;
+        let row = &mut self.data[start..start + row_len::<P>(self.width)];
+        Some(P::slice_from_components_mut(row).unwrap())
+    }
+    /// Returns an iterator over the rows of the view.
+    pub fn rows(&self) -> impl Iterator<Item = &[P]> {
+        self.as_view().rows()
+    }
+    /// Returns an iterator over the rows of the view, mutably.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [P]> {
+        let (height, stride, row_len) = (self.height, self.stride, row_len::<P>(self.width));
+        let mut data = &mut *self.data;
+        (0..height).map(move |y| {
+            let rest = core::mem::take(&mut data);
+            let (row, rest) = rest.split_at_mut(if y + 1 < height { stride } else { rest.len() });
+            data = rest;
+            P::slice_from_components_mut(&mut row[..row_len]).unwrap()
+        })
+    }
+
+    /// Returns a view of the rectangle at column `x` and row `y` with the given size, or `None`
+    /// if it does not fit in this view.
+    pub fn sub_image(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<ImageView<'_, P>> {
+        self.as_view().sub_image(x, y, width, height)
+    }
+    /// Returns a mutable view of the rectangle at column `x` and row `y` with the given size, or
+    /// `None` if it does not fit in this view.
+    pub fn sub_image_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<ImageViewMut<'_, P>> {
+        self.reborrow().into_sub_image(x, y, width, height)
+    }
+    /// Converts the view into a mutable view of the rectangle at column `x` and row `y` with the
+    /// given size, or `None` if it does not fit in this view.
+    pub fn into_sub_image(self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        let range =
+            sub_image_range::<P>(self.width, self.height, self.stride, x, y, width, height)?;
+        Some(Self {
+            data: &mut self.data[range],
+            width,
+            height,
+            stride: self.stride,
+        })
+    }
+
+    /// Returns an immutable view of the same pixels.
+    pub fn as_view(&self) -> ImageView<'_, P> {
+        ImageView {
+            data: self.data,
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+        }
+    }
+    /// Returns a shorter-lived mutable view of the same pixels.
+    pub fn reborrow(&mut self) -> ImageViewMut<'_, P> {
+        ImageViewMut {
+            data: self.data,
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An `Indexed` pixel, whose color is the color at its index in a palette.
+///
+/// The palette is stored separately, such as in a [`Palette`](crate::Palette).
+pub struct Indexed<I> {
+    /// Palette Index Component
+    pub index: I,
+}
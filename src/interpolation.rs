@@ -0,0 +1,123 @@
+use crate::*;
+
+/// How [`Interpolation::lerp()`] mixes the components of two pixels.
+///
+/// Components are normalized to `f64` using [`FromComponentCommon`], interpolated, then converted
+/// back, so integer components are rounded to the nearest value. The alpha component is always
+/// interpolated directly, and pixels without an alpha component are treated as fully opaque.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let red = Rgba::<u8> { r: 255, g: 0, b: 0, a: 255 };
+/// let clear = Rgba::<u8> { r: 0, g: 0, b: 255, a: 0 };
+///
+/// // Straight interpolation lets the color of a transparent pixel bleed through.
+/// assert_eq!(lerp(red, clear, 0.5), Rgba { r: 128, g: 0, b: 128, a: 128 });
+/// assert_eq!(
+///     Interpolation::Premultiplied.lerp(red, clear, 0.5),
+///     Rgba { r: 255, g: 0, b: 0, a: 128 }
+/// );
+///
+/// let black = Rgb::<u8> { r: 0, g: 0, b: 0 };
+/// let white = Rgb::<u8> { r: 255, g: 255, b: 255 };
+///
+/// assert_eq!(lerp(black, white, 0.5), Rgb { r: 128, g: 128, b: 128 });
+/// assert_eq!(
+///     Interpolation::LinearLight.lerp(black, white, 0.5),
+///     Rgb { r: 188, g: 188, b: 188 }
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Interpolation {
+    /// Interpolates the components as they are.
+    #[default]
+    Straight,
+    /// Interpolates the color components premultiplied by the alpha component, so that the
+    /// color of a transparent pixel does not affect the result.
+    Premultiplied,
+    /// Interpolates the color components in linear light by decoding and re-encoding them with
+    /// the sRGB transfer function.
+    LinearLight,
+    /// Combines [`Interpolation::Premultiplied`] and [`Interpolation::LinearLight`].
+    PremultipliedLinearLight,
+}
+
+impl Interpolation {
+    /// Interpolates from `start` at `t == 0.0` to `end` at `t == 1.0`.
+    ///
+    /// Values of `t` outside `0.0..=1.0` extrapolate, with integer components clamped to their
+    /// range.
+    pub fn lerp<P>(self, start: P, end: P, t: f64) -> P
+    where
+        P: Pixel,
+        P::Component: FromComponentCommon<f64>,
+        f64: FromComponentCommon<P::Component>,
+    {
+        let premultiplied = matches!(
+            self,
+            Interpolation::Premultiplied | Interpolation::PremultipliedLinearLight
+        );
+        let linear_light = matches!(
+            self,
+            Interpolation::LinearLight | Interpolation::PremultipliedLinearLight
+        );
+
+        let start_alpha = start.alpha().map_or(1.0, f64::from_component_common);
+        let end_alpha = end.alpha().map_or(1.0, f64::from_component_common);
+        let alpha = mix(start_alpha, end_alpha, t);
+
+        let decode = |color: P::Component, alpha: f64| {
+            let color = f64::from_component_common(color);
+            let color = if linear_light {
+                color.srgb_to_linear()
+            } else {
+                color
+            };
+            if premultiplied {
+                color * alpha
+            } else {
+                color
+            }
+        };
+        let encode = |color: f64| {
+            let color = match premultiplied {
+                true if alpha > 0.0 => color / alpha,
+                true => 0.0,
+                false => color,
+            };
+            let color = if linear_light {
+                color.linear_to_srgb()
+            } else {
+                color
+            };
+            P::Component::from_component_common(color)
+        };
+
+        let colors = start
+            .color_array()
+            .into_iter()
+            .zip(end.color_array())
+            .map(|(start, end)| encode(mix(decode(start, start_alpha), decode(end, end_alpha), t)));
+
+        P::from_colors_alpha(colors, P::Component::from_component_common(alpha))
+    }
+}
+
+/// Interpolates from `start` at `t == 0.0` to `end` at `t == 1.0` using
+/// [`Interpolation::Straight`].
+pub fn lerp<P>(start: P, end: P, t: f64) -> P
+where
+    P: Pixel,
+    P::Component: FromComponentCommon<f64>,
+    f64: FromComponentCommon<P::Component>,
+{
+    Interpolation::Straight.lerp(start, end, t)
+}
+
+/// Mixes two values, returning exactly `start` and `end` when `t` is `0.0` and `1.0`.
+fn mix(start: f64, end: f64, t: f64) -> f64 {
+    start * (1.0 - t) + end * t
+}
@@ -0,0 +1,12 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A CIE `L*a*b*` pixel.
+pub struct Lab<T> {
+    /// Lightness Component
+    pub l: T,
+    /// Green-Red Component
+    pub a: T,
+    /// Blue-Yellow Component
+    pub b: T,
+}
@@ -0,0 +1,12 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A CIE `LCh` pixel, the cylindrical form of [`Lab`](crate::Lab).
+pub struct Lch<T> {
+    /// Lightness Component
+    pub l: T,
+    /// Chroma Component
+    pub c: T,
+    /// Hue Component, in degrees
+    pub h: T,
+}
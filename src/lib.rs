@@ -3,35 +3,150 @@
 #![warn(missing_docs)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod abgr;
 mod argb;
 mod bgr;
 mod bgra;
+#[cfg(all(feature = "alloc", feature = "libm"))]
+mod gradient;
 mod gray;
 mod gray_alpha;
+mod hsl;
+mod hsla;
+mod hsv;
+mod hsva;
+mod hwb;
+mod hwba;
+#[cfg(feature = "alloc")]
+mod image_buffer;
+mod image_view;
+mod indexed;
+mod lab;
+mod laba;
+mod lch;
+mod lcha;
+mod linear;
+mod normalized;
+mod oklab;
+mod oklaba;
+mod oklch;
+mod oklcha;
+mod packed;
+#[cfg(all(feature = "alloc", feature = "libm"))]
+mod palette;
+mod premultiplied;
 mod rgb;
 mod rgba;
+mod xyz;
+mod xyza;
+mod y_cb_cr;
+mod y_cb_cr_a;
 
+mod arithmetic;
+#[cfg(feature = "libm")]
+mod compositing;
+#[cfg(feature = "libm")]
+mod distance;
+#[cfg(all(feature = "alloc", feature = "libm"))]
+mod dither;
+mod error;
+mod frame;
 mod from;
 #[cfg(feature = "libm")]
 mod from_pixel_common;
+#[cfg(feature = "libm")]
+mod interpolation;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod pixel;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "libm")]
+mod srgb;
+mod swizzle;
 mod with_alpha;
 
 pub use abgr::Abgr;
 pub use argb::Argb;
 pub use bgr::Bgr;
 pub use bgra::Bgra;
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub use gradient::Gradient;
 pub use gray::Gray;
 pub use gray_alpha::GrayAlpha;
+pub use hsl::Hsl;
+pub use hsla::Hsla;
+pub use hsv::Hsv;
+pub use hsva::Hsva;
+pub use hwb::Hwb;
+pub use hwba::Hwba;
+#[cfg(feature = "alloc")]
+pub use image_buffer::ImageBuffer;
+pub use image_view::{ImageView, ImageViewMut};
+pub use indexed::Indexed;
+pub use lab::Lab;
+pub use laba::Laba;
+pub use lch::Lch;
+pub use lcha::Lcha;
+pub use linear::Linear;
+pub use normalized::{Snorm, Unorm};
+pub use oklab::Oklab;
+pub use oklaba::Oklaba;
+pub use oklch::Oklch;
+pub use oklcha::Oklcha;
+pub use packed::{Argb1555, Bgr565, Rgb10a2, Rgb565, Rgba4444, Rgba5551};
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub use palette::Palette;
+pub use premultiplied::Premultiplied;
 pub use rgb::Rgb;
 pub use rgba::Rgba;
+pub use xyz::Xyz;
+pub use xyza::Xyza;
+pub use y_cb_cr::YCbCr;
+pub use y_cb_cr_a::YCbCrA;
 
+pub use arithmetic::{ArithmeticComponent, Operand, PixelArithmetic};
+#[cfg(feature = "libm")]
+pub use compositing::{blend_mode::BlendMode, porter_duff::PorterDuff};
+#[cfg(feature = "libm")]
+pub use distance::ColorDistance;
+#[cfg(all(feature = "alloc", feature = "libm"))]
+pub use dither::Dither;
+pub use error::{
+    CastError, Error, FromComponentsError, LayoutError, LengthMismatchError, PaletteError,
+};
+pub use frame::{
+    packed::{Uyvy, Yuy2},
+    planar::I420,
+    semi_planar::{Nv12, Nv21, P010},
+    YCbCrFrame,
+};
 #[cfg(feature = "libm")]
 pub use from_pixel_common::{
-    enlargeable::Enlargeable, from_component_common::FromComponentCommon, FromPixelCommon,
+    chromatic_adaptation::ChromaticAdaptation,
+    convert_slice,
+    enlargeable::Enlargeable,
+    from_component_common::FromComponentCommon,
+    perceptual::WhitePoint,
+    y_cb_cr::{YCbCrMatrix, YCbCrRange},
+    FromPixelCommon,
 };
+#[cfg(feature = "libm")]
+pub use interpolation::{lerp, Interpolation};
+#[cfg(all(feature = "rayon", feature = "libm"))]
+pub use parallel::par_convert_slice;
+#[cfg(feature = "rayon")]
+pub use parallel::{par_map_alpha, par_map_colors, par_map_components};
 pub use pixel::{
-    as_slice::AsSlice, contiguous_pixel::ContiguousPixel, pixel_component::PixelComponent, Pixel,
+    as_slice::AsSlice,
+    contiguous_pixel::ContiguousPixel,
+    pixel_component::{PixelComponent, PodComponent},
+    Pixel,
 };
+#[cfg(feature = "libm")]
+pub use srgb::SrgbComponent;
+pub use swizzle::swizzle_slice;
 pub use with_alpha::{WithAlpha, WithoutAlpha};
@@ -0,0 +1,27 @@
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A pixel whose color components are in linear light rather than encoded with the sRGB transfer
+/// function.
+///
+/// Pixels which are not wrapped in `Linear` are assumed to be sRGB encoded. Wrapping a pixel does
+/// not change its components, so use [`Linear::from_srgb()`] to decode an sRGB encoded pixel, or
+/// construct `Linear(pixel)` directly if `pixel` is already in linear light.
+///
+/// Converting between `Linear` pixels with [`FromPixelCommon`](crate::FromPixelCommon) converts
+/// the wrapped pixels, so converting to [`Gray`](crate::Gray) gives the true CIE Y luminance
+/// rather than the luma of the encoded components.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let red = Rgb::<f32> { r: 1.0, g: 0.0, b: 0.0 };
+///
+/// let luma = Gray::<f32>::from_pixel_common(red);
+/// let luminance = Linear::<Gray<f32>>::from_pixel_common(Linear::from_srgb(red));
+///
+/// assert!((luma.gray - 0.2126).abs() < 1e-6);
+/// assert!((luminance.to_srgb().gray - 0.4985).abs() < 1e-4);
+/// ```
+pub struct Linear<P>(pub P);
@@ -0,0 +1,147 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use num_traits::{Num, NumCast, One, ToPrimitive, Zero};
+
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An unsigned normalized component storing a `BITS`-bit value in the unsigned integer `T`, where
+/// `0` means `0.0` and `2^BITS - 1` means `1.0`.
+///
+/// Arithmetic operates on the stored values, while conversions with
+/// [`FromComponentCommon`](crate::FromComponentCommon) respect the bit depth. Conversions
+/// between `Unorm`s with the same `T` but different `BITS` are not available, convert via `f64`
+/// instead.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// // 12-bit sensor data stored in a `u16`.
+/// let white = Unorm::<u16, 12>(4095);
+///
+/// assert_eq!(Unorm::<u16, 12>::COMPONENT_MAX, white);
+/// assert_eq!(f32::from_component_common(white), 1.0);
+/// assert_eq!(u16::from_component_common(white), u16::MAX);
+/// assert_eq!(Unorm::<u16, 12>::from_component_common(0.5_f32), Unorm(2048));
+///
+/// let rgb = Rgb::<Unorm<u16, 12>>::from_pixel_common(Rgb::<u8> { r: 255, g: 0, b: 0 });
+/// assert_eq!(rgb.r, white);
+/// ```
+pub struct Unorm<T, const BITS: u32>(pub T);
+
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A signed normalized component storing a value in the signed integer `T`, where `-T::MAX` means
+/// `-1.0` and `T::MAX` means `1.0`.
+///
+/// As on GPUs, `T::MIN` also means `-1.0`, and converting to a float gives values in
+/// `-1.0..=1.0` while converting to any other component type clamps negative values to the
+/// minimum.
+///
+/// Arithmetic operates on the stored values, while conversions with
+/// [`FromComponentCommon`](crate::FromComponentCommon) respect the signed range.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// assert_eq!(f32::from_component_common(Snorm(-128_i8)), -1.0);
+/// assert_eq!(f32::from_component_common(Snorm(-127_i8)), -1.0);
+/// assert_eq!(f32::from_component_common(Snorm(127_i8)), 1.0);
+///
+/// assert_eq!(Snorm::<i8>::from_component_common(-0.5_f32), Snorm(-64));
+/// assert_eq!(u8::from_component_common(Snorm(-64_i8)), 0);
+/// assert_eq!(u8::from_component_common(Snorm(127_i8)), 255);
+///
+/// assert_eq!(num_traits::cast::<i32, Snorm<i8>>(-128), Some(Snorm(-128)));
+/// ```
+pub struct Snorm<T>(pub T);
+
+macro_rules! forward_binary_operator {
+    ($normalized:ty, [$($generics:tt)*], $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<T: $trait<Output = T>, $($generics)*> $trait for $normalized {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self(self.0.$method(rhs.0))
+            }
+        }
+        impl<T: $assign_trait, $($generics)*> $assign_trait for $normalized {
+            fn $assign_method(&mut self, rhs: Self) {
+                self.0.$assign_method(rhs.0)
+            }
+        }
+    };
+}
+macro_rules! implement_normalized {
+    ($normalized:ty, [$($generics:tt)*]) => {
+        forward_binary_operator!($normalized, [$($generics)*], Add, add, AddAssign, add_assign);
+        forward_binary_operator!($normalized, [$($generics)*], Sub, sub, SubAssign, sub_assign);
+        forward_binary_operator!($normalized, [$($generics)*], Mul, mul, MulAssign, mul_assign);
+        forward_binary_operator!($normalized, [$($generics)*], Div, div, DivAssign, div_assign);
+        forward_binary_operator!($normalized, [$($generics)*], Rem, rem, RemAssign, rem_assign);
+
+        impl<T: Zero, $($generics)*> Zero for $normalized {
+            fn zero() -> Self {
+                Self(T::zero())
+            }
+            fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+        }
+        impl<T: One, $($generics)*> One for $normalized {
+            fn one() -> Self {
+                Self(T::one())
+            }
+        }
+        impl<T: Num, $($generics)*> Num for $normalized {
+            type FromStrRadixErr = T::FromStrRadixErr;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                T::from_str_radix(str, radix).map(Self)
+            }
+        }
+        impl<T: ToPrimitive, $($generics)*> ToPrimitive for $normalized {
+            fn to_i64(&self) -> Option<i64> {
+                self.0.to_i64()
+            }
+            fn to_u64(&self) -> Option<u64> {
+                self.0.to_u64()
+            }
+            fn to_i128(&self) -> Option<i128> {
+                self.0.to_i128()
+            }
+            fn to_u128(&self) -> Option<u128> {
+                self.0.to_u128()
+            }
+        }
+    };
+}
+implement_normalized!(Unorm<T, BITS>, [const BITS: u32]);
+implement_normalized!(Snorm<T>, []);
+
+// `NumCast` casts the stored value, failing if it is outside the range of valid encodings. That is
+// the component range for `Unorm`, and every value of `T` for `Snorm` since `T::MIN` also encodes
+// `-1.0`.
+macro_rules! implement_num_cast {
+    ($normalized:ty, $int:ident, $min:expr, [$($generics:tt)*]) => {
+        impl<$($generics)*> NumCast for $normalized {
+            fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+                use crate::PixelComponent;
+
+                let n = <$int as NumCast>::from(n)?;
+                ($min..=Self::COMPONENT_MAX.0).contains(&n).then_some(Self(n))
+            }
+        }
+    };
+}
+implement_num_cast!(Unorm<u8, BITS>, u8, 0, [const BITS: u32]);
+implement_num_cast!(Unorm<u16, BITS>, u16, 0, [const BITS: u32]);
+implement_num_cast!(Unorm<u32, BITS>, u32, 0, [const BITS: u32]);
+implement_num_cast!(Unorm<u64, BITS>, u64, 0, [const BITS: u32]);
+implement_num_cast!(Snorm<i8>, i8, i8::MIN, []);
+implement_num_cast!(Snorm<i16>, i16, i16::MIN, []);
+implement_num_cast!(Snorm<i32>, i32, i32::MIN, []);
+implement_num_cast!(Snorm<i64>, i64, i64::MIN, []);
@@ -0,0 +1,14 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An `Oklab` pixel with an alpha component.
+pub struct Oklaba<T> {
+    /// Lightness Component
+    pub l: T,
+    /// Green-Red Component
+    pub a: T,
+    /// Blue-Yellow Component
+    pub b: T,
+    /// Alpha Component
+    pub alpha: T,
+}
@@ -0,0 +1,12 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An `Oklch` pixel, the cylindrical form of [`Oklab`](crate::Oklab).
+pub struct Oklch<T> {
+    /// Lightness Component
+    pub l: T,
+    /// Chroma Component
+    pub c: T,
+    /// Hue Component, in degrees
+    pub h: T,
+}
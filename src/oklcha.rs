@@ -0,0 +1,14 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// An `Oklch` pixel with an alpha component.
+pub struct Oklcha<T> {
+    /// Lightness Component
+    pub l: T,
+    /// Chroma Component
+    pub c: T,
+    /// Hue Component, in degrees
+    pub h: T,
+    /// Alpha Component
+    pub a: T,
+}
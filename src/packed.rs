@@ -0,0 +1,117 @@
+/// The bit width of each component of a packed pixel, which is zero for a missing component.
+#[cfg_attr(not(feature = "libm"), allow(dead_code))]
+pub(crate) struct Bits {
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+    pub a: u32,
+}
+
+macro_rules! packed {
+    ($(#[$meta:meta])* $name:ident, $int:ident, $(($field:ident, $color:literal, $shift:literal, $bits:literal)),+) => {
+        #[repr(transparent)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        $(#[$meta])*
+        pub struct $name(pub $int);
+        impl $name {
+            #[cfg_attr(not(feature = "libm"), allow(dead_code))]
+            pub(crate) const BITS: Bits = {
+                let mut bits = Bits { r: 0, g: 0, b: 0, a: 0 };
+                $(bits.$field = $bits;)+
+                bits
+            };
+
+            /// Packs the given components, ignoring any bits beyond the width of each component.
+            pub const fn new($($field: $int),+) -> Self {
+                Self($((($field & ((1 << $bits) - 1)) << $shift))|+)
+            }
+
+            $(
+                #[doc = concat!("Returns the ", $bits, "-bit ", $color, " component.")]
+                pub const fn $field(self) -> $int {
+                    (self.0 >> $shift) & ((1 << $bits) - 1)
+                }
+            )+
+        }
+    };
+}
+
+packed!(
+    /// An `RGB` pixel packed into a `u16` with 5 bits of red, 6 bits of green and 5 bits of
+    /// blue, from the most to the least significant bit.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let pixel = Rgb565::new(31, 32, 0);
+    /// assert_eq!(pixel.0, 0b11111_100000_00000);
+    /// assert_eq!(pixel.g(), 32);
+    ///
+    /// let rgb = Rgb::<u8>::from_pixel_common(pixel);
+    /// assert_eq!(rgb, Rgb { r: 255, g: 130, b: 0 });
+    /// assert_eq!(Rgb565::from_pixel_common(rgb), pixel);
+    ///
+    /// let mut packed = [Rgb565::default(); 2];
+    /// convert_slice(&[rgb, rgb], &mut packed).unwrap();
+    /// assert_eq!(packed, [pixel; 2]);
+    /// ```
+    Rgb565,
+    u16,
+    (r, "red", 11, 5),
+    (g, "green", 5, 6),
+    (b, "blue", 0, 5)
+);
+packed!(
+    /// A `BGR` pixel packed into a `u16` with 5 bits of blue, 6 bits of green and 5 bits of
+    /// red, from the most to the least significant bit.
+    Bgr565,
+    u16,
+    (r, "red", 0, 5),
+    (g, "green", 5, 6),
+    (b, "blue", 11, 5)
+);
+packed!(
+    /// An `RGBA` pixel packed into a `u16` with 4 bits of each of red, green, blue and
+    /// alpha, from the most to the least significant bit.
+    Rgba4444,
+    u16,
+    (r, "red", 12, 4),
+    (g, "green", 8, 4),
+    (b, "blue", 4, 4),
+    (a, "alpha", 0, 4)
+);
+packed!(
+    /// An `ARGB` pixel packed into a `u16` with 1 bit of alpha and 5 bits of each of red,
+    /// green and blue, from the most to the least significant bit.
+    Argb1555,
+    u16,
+    (r, "red", 10, 5),
+    (g, "green", 5, 5),
+    (b, "blue", 0, 5),
+    (a, "alpha", 15, 1)
+);
+packed!(
+    /// An `RGBA` pixel packed into a `u16` with 5 bits of each of red, green and blue and 1
+    /// bit of alpha, from the most to the least significant bit.
+    Rgba5551,
+    u16,
+    (r, "red", 11, 5),
+    (g, "green", 6, 5),
+    (b, "blue", 1, 5),
+    (a, "alpha", 0, 1)
+);
+packed!(
+    /// An `RGBA` pixel packed into a `u32` with 10 bits of each of red, green and blue and
+    /// 2 bits of alpha, from the least to the most significant bit.
+    ///
+    /// This is the layout of `DXGI_FORMAT_R10G10B10A2_UNORM` and
+    /// `VK_FORMAT_A2B10G10R10_UNORM_PACK32`.
+    Rgb10a2,
+    u32,
+    (r, "red", 0, 10),
+    (g, "green", 10, 10),
+    (b, "blue", 20, 10),
+    (a, "alpha", 30, 2)
+);
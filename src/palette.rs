@@ -0,0 +1,434 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use num_traits::PrimInt;
+
+use crate::*;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+/// A palette of colors referenced by [`Indexed`] pixels.
+///
+/// A palette representing an image can be found with [`Palette::median_cut()`] or
+/// [`Palette::k_means()`], then the image is quantized by replacing each pixel with the index of
+/// its nearest palette color and expanded back by looking up each index.
+///
+/// Unless a distance is given, colors are compared by the Euclidean distance between their
+/// components normalized to `f64` using [`FromComponentCommon`]. This works for any pixel type and
+/// includes the alpha component, so transparent pixels are kept apart from opaque ones.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let image = [
+///     Rgba::<u8> { r: 250, g: 10, b: 10, a: 255 },
+///     Rgba::<u8> { r: 10, g: 10, b: 250, a: 255 },
+///     Rgba::<u8> { r: 240, g: 0, b: 20, a: 255 },
+///     Rgba::<u8> { r: 0, g: 20, b: 240, a: 255 },
+/// ];
+///
+/// let palette = Palette::median_cut(&image, 2);
+/// assert_eq!(palette.len(), 2);
+///
+/// let mut indexed = [Indexed::<u8>::default(); 4];
+/// palette.quantize_slice(&image, &mut indexed).unwrap();
+/// assert_eq!(indexed[0], indexed[2]);
+/// assert_eq!(indexed[1], indexed[3]);
+///
+/// let mut expanded = [Rgba::default(); 4];
+/// palette.expand_slice(&indexed, &mut expanded).unwrap();
+/// assert_eq!(expanded[0], Rgba { r: 245, g: 5, b: 15, a: 255 });
+/// assert_eq!(palette.get(indexed[1]), Some(Rgba { r: 5, g: 15, b: 245, a: 255 }));
+/// assert_eq!(palette.get(Indexed { index: 2_u8 }), None);
+///
+/// let palette = Palette::new((0..=300).map(|gray| Gray::<u16> { gray }));
+/// let mut indexed = [Indexed::<u8>::default(); 1];
+/// assert_eq!(
+///     palette.quantize_slice(&[Gray { gray: 7 }], &mut indexed),
+///     Err(PaletteError::TooManyColors { len: 301 })
+/// );
+/// assert_eq!(
+///     Palette::<Gray<u16>>::default().quantize_slice(&[Gray { gray: 7 }], &mut indexed),
+///     Err(PaletteError::Empty)
+/// );
+/// ```
+pub struct Palette<P> {
+    colors: Vec<P>,
+}
+
+impl<P> Palette<P>
+where
+    P: Pixel,
+{
+    /// Creates a palette from its colors, in index order.
+    pub fn new(colors: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            colors: colors.into_iter().collect(),
+        }
+    }
+
+    /// Returns the colors in index order.
+    pub fn colors(&self) -> &[P] {
+        &self.colors
+    }
+    /// Returns the number of colors.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+    /// Returns `true` if the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+    /// Returns the color at the index of `pixel`, or [`None`] if the index is out of range.
+    pub fn get<I>(&self, pixel: Indexed<I>) -> Option<P>
+    where
+        I: PrimInt,
+    {
+        self.colors
+            .get(num_traits::cast::<I, usize>(pixel.index)?)
+            .copied()
+    }
+
+    /// Returns the index of the color nearest to `color` by the given `distance`, or [`None`] if
+    /// the palette is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let palette = Palette::new([
+    ///     Rgb::<u8> { r: 0, g: 0, b: 0 },
+    ///     Rgb::<u8> { r: 255, g: 255, b: 255 },
+    ///     Rgb::<u8> { r: 0, g: 0, b: 255 },
+    /// ]);
+    /// let navy = Rgb::<u8> { r: 0, g: 0, b: 100 };
+    ///
+    /// // Navy is nearer to black in sRGB, but looks nearer to blue.
+    /// assert_eq!(palette.nearest(navy), Some(0));
+    /// assert_eq!(
+    ///     palette.nearest_by(navy, |a, b| ColorDistance::Ciede2000.distance(a, b)),
+    ///     Some(2)
+    /// );
+    /// ```
+    pub fn nearest_by(&self, color: P, mut distance: impl FnMut(P, P) -> f64) -> Option<usize> {
+        nearest_by(&self.colors, |candidate| distance(color, candidate))
+    }
+    /// Replaces each pixel in `source` with the index of its nearest color in the palette by the
+    /// given `distance`, as in [`Palette::nearest_by()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaletteError`] and leaves `destination` untouched if the two slices are not the
+    /// same length, the palette is empty or `I` cannot hold the index of every color.
+    pub fn quantize_slice_by<I>(
+        &self,
+        source: &[P],
+        destination: &mut [Indexed<I>],
+        mut distance: impl FnMut(P, P) -> f64,
+    ) -> Result<(), PaletteError>
+    where
+        I: PrimInt,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        self.check_indices::<I>()?;
+        for (source, destination) in source.iter().zip(destination) {
+            let nearest = nearest_by(&self.colors, |candidate| distance(*source, candidate));
+            *destination = indexed(nearest.unwrap());
+        }
+        Ok(())
+    }
+    /// Replaces each index in `source` with its color in the palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices are not
+    /// the same length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of range.
+    pub fn expand_slice<I>(
+        &self,
+        source: &[Indexed<I>],
+        destination: &mut [P],
+    ) -> Result<(), LengthMismatchError>
+    where
+        I: PrimInt,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        for (source, destination) in source.iter().zip(destination) {
+            *destination = self.get(*source).expect("palette index out of range");
+        }
+        Ok(())
+    }
+
+    /// Checks that the palette has colors and that `I` can hold the index of every color.
+    pub(crate) fn check_indices<I>(&self) -> Result<(), PaletteError>
+    where
+        I: PrimInt,
+    {
+        match self.len() {
+            0 => Err(PaletteError::Empty),
+            len if num_traits::cast::<usize, I>(len - 1).is_none() => {
+                Err(PaletteError::TooManyColors { len })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<P> Palette<P>
+where
+    P: Pixel,
+    P::Component: FromComponentCommon<f64>,
+    f64: FromComponentCommon<P::Component>,
+{
+    /// Finds a palette of up to `max_colors` colors representing `pixels` using the median cut
+    /// algorithm.
+    ///
+    /// Starting from a box around every pixel, the box with the widest range of any component is
+    /// repeatedly split at the median of that component, until there are `max_colors` boxes or
+    /// every box holds a single color. The palette has the average color of each box.
+    pub fn median_cut(pixels: &[P], max_colors: usize) -> Self {
+        let mut points: Vec<_> = pixels.iter().map(|pixel| normalize(*pixel)).collect();
+        let boxes = median_cut(&mut points, max_colors);
+        Self::new(
+            boxes
+                .into_iter()
+                .map(|range| denormalize(mean(&points[range]))),
+        )
+    }
+    /// Finds a palette of up to `max_colors` colors representing `pixels` using k-means
+    /// clustering, starting from the [`Palette::median_cut()`] colors.
+    ///
+    /// Each iteration moves every color to the average of the pixels nearest to it, stopping
+    /// early once no color moves. This is slower than median cut but usually more accurate.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let image: Vec<_> = (0..=255)
+    ///     .map(|gray| Gray::<u8> { gray: if gray < 192 { 0 } else { gray } })
+    ///     .collect();
+    ///
+    /// // Median cut splits the range of grays in half, whereas k-means moves the colors to the
+    /// // clusters.
+    /// let median_cut = Palette::median_cut(&image, 2);
+    /// let k_means = Palette::k_means(&image, 2, 16);
+    ///
+    /// assert_eq!(median_cut.colors(), [Gray { gray: 0 }, Gray { gray: 112 }]);
+    /// assert_eq!(k_means.colors(), [Gray { gray: 0 }, Gray { gray: 223 }]);
+    /// ```
+    pub fn k_means(pixels: &[P], max_colors: usize, iterations: usize) -> Self {
+        let mut points: Vec<_> = pixels.iter().map(|pixel| normalize(*pixel)).collect();
+        let mut centroids: Vec<_> = median_cut(&mut points, max_colors)
+            .into_iter()
+            .map(|range| mean(&points[range]))
+            .collect();
+
+        let count = usize::from(P::COMPONENT_COUNT);
+        let mut sums = vec![0.0; centroids.len() * count];
+        let mut counts = vec![0_usize; centroids.len()];
+        for _ in 0..iterations {
+            sums.fill(0.0);
+            counts.fill(0);
+            for point in &points {
+                let nearest = nearest(&centroids, *point);
+                counts[nearest] += 1;
+                for (sum, component) in sums[nearest * count..][..count]
+                    .iter_mut()
+                    .zip(point.component_array())
+                {
+                    *sum += component;
+                }
+            }
+
+            let mut moved = false;
+            for ((centroid, sums), count) in
+                centroids.iter_mut().zip(sums.chunks(count)).zip(&counts)
+            {
+                // A color nearest to no pixels stays where it is.
+                if *count == 0 {
+                    continue;
+                }
+                let moved_centroid =
+                    P::SelfType::<f64>::from_components(sums.iter().map(|sum| sum / *count as f64));
+                moved |= moved_centroid.component_array().as_slice()
+                    != centroid.component_array().as_slice();
+                *centroid = moved_centroid;
+            }
+            if !moved {
+                break;
+            }
+        }
+
+        Self::new(centroids.into_iter().map(denormalize))
+    }
+
+    /// Returns the index of the color nearest to `color`, or [`None`] if the palette is empty.
+    pub fn nearest(&self, color: P) -> Option<usize> {
+        let color = normalize(color);
+        nearest_by(&self.colors, |candidate| {
+            squared_distance(color, normalize(candidate))
+        })
+    }
+    /// Replaces each pixel in `source` with the index of its nearest color in the palette, as in
+    /// [`Palette::nearest()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaletteError`] and leaves `destination` untouched if the two slices are not the
+    /// same length, the palette is empty or `I` cannot hold the index of every color.
+    pub fn quantize_slice<I>(
+        &self,
+        source: &[P],
+        destination: &mut [Indexed<I>],
+    ) -> Result<(), PaletteError>
+    where
+        I: PrimInt,
+    {
+        LengthMismatchError::check(source.len(), destination.len())?;
+        self.check_indices::<I>()?;
+        let colors: Vec<_> = self.colors.iter().map(|color| normalize(*color)).collect();
+        for (source, destination) in source.iter().zip(destination) {
+            *destination = indexed(nearest(&colors, normalize(*source)));
+        }
+        Ok(())
+    }
+}
+
+/// A pixel with its components normalized to `f64`, which quantizers work with.
+pub(crate) type Point<P> = <P as Pixel>::SelfType<f64>;
+
+pub(crate) fn normalize<P>(pixel: P) -> Point<P>
+where
+    P: Pixel,
+    f64: FromComponentCommon<P::Component>,
+{
+    pixel.map_components(f64::from_component_common)
+}
+fn denormalize<P>(point: Point<P>) -> P
+where
+    P: Pixel,
+    P::Component: FromComponentCommon<f64>,
+{
+    point.map_components(P::Component::from_component_common)
+}
+pub(crate) fn indexed<I>(index: usize) -> Indexed<I>
+where
+    I: PrimInt,
+{
+    Indexed {
+        index: num_traits::cast(index).unwrap(),
+    }
+}
+
+fn component<Q>(point: Q, axis: usize) -> f64
+where
+    Q: Pixel<Component = f64>,
+{
+    point.component_array().as_slice()[axis]
+}
+pub(crate) fn squared_distance<Q>(a: Q, b: Q) -> f64
+where
+    Q: Pixel<Component = f64>,
+{
+    a.component_array()
+        .into_iter()
+        .zip(b.component_array())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum()
+}
+fn mean<Q>(points: &[Q]) -> Q
+where
+    Q: Pixel<Component = f64>,
+{
+    let len = points.len() as f64;
+    Q::from_components((0..usize::from(Q::COMPONENT_COUNT)).map(|axis| {
+        let sum: f64 = points.iter().map(|point| component(*point, axis)).sum();
+        sum / len
+    }))
+}
+
+/// Returns the index of the first item with the smallest `distance`.
+fn nearest_by<T>(items: &[T], mut distance: impl FnMut(T) -> f64) -> Option<usize>
+where
+    T: Copy,
+{
+    items
+        .iter()
+        .map(|item| distance(*item))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+pub(crate) fn nearest<Q>(points: &[Q], point: Q) -> usize
+where
+    Q: Pixel<Component = f64>,
+{
+    nearest_by(points, |candidate| squared_distance(point, candidate)).unwrap()
+}
+
+/// A range of points in median cut, along with the component with the widest range.
+struct ColorBox {
+    range: Range<usize>,
+    axis: usize,
+    width: f64,
+}
+impl ColorBox {
+    fn new<Q>(points: &[Q], range: Range<usize>) -> Self
+    where
+        Q: Pixel<Component = f64>,
+    {
+        let (axis, width) = (0..usize::from(Q::COMPONENT_COUNT))
+            .map(|axis| {
+                let (min, max) = points[range.clone()].iter().fold(
+                    (f64::INFINITY, f64::NEG_INFINITY),
+                    |(min, max), point| {
+                        let component = component(*point, axis);
+                        (min.min(component), max.max(component))
+                    },
+                );
+                (axis, max - min)
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        Self { range, axis, width }
+    }
+}
+
+/// Splits `points` into up to `max_colors` ranges, sorting `points` so each range is contiguous.
+fn median_cut<Q>(points: &mut [Q], max_colors: usize) -> Vec<Range<usize>>
+where
+    Q: Pixel<Component = f64>,
+{
+    let mut boxes = Vec::new();
+    if !points.is_empty() && max_colors > 0 {
+        boxes.push(ColorBox::new(points, 0..points.len()));
+    }
+
+    while boxes.len() < max_colors {
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, color_box)| color_box.width > 0.0)
+            .max_by(|(_, a), (_, b)| a.width.total_cmp(&b.width));
+        let Some((widest, _)) = widest else {
+            break;
+        };
+
+        let ColorBox { range, axis, .. } = boxes.swap_remove(widest);
+        points[range.clone()]
+            .sort_unstable_by(|a, b| component(*a, axis).total_cmp(&component(*b, axis)));
+        let middle = range.start + range.len() / 2;
+        boxes.push(ColorBox::new(points, range.start..middle));
+        boxes.push(ColorBox::new(points, middle..range.end));
+    }
+
+    let mut ranges: Vec<_> = boxes.into_iter().map(|color_box| color_box.range).collect();
+    ranges.sort_unstable_by_key(|range| range.start);
+    ranges
+}
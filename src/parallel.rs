@@ -0,0 +1,150 @@
+use rayon::prelude::*;
+
+use crate::*;
+
+/// The number of bytes of pixels each parallel task works on, which is small enough to stay in
+/// a core's cache while large enough to keep scheduling overhead low.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Returns the number of pixels in each chunk when working on pixels of the given size.
+fn chunk_len(pixel_size: usize) -> usize {
+    (CHUNK_BYTES / pixel_size.max(1)).max(1)
+}
+
+/// Converts each pixel in `source` into the same position in `destination` using
+/// [`FromPixelCommon`], in parallel.
+///
+/// The result is identical to [`convert_slice()`].
+///
+/// # Errors
+///
+/// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices are not
+/// the same length.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let frame: Vec<_> = (0..100_000)
+///     .map(|i| Rgba::<u8> { r: i as u8, g: (i >> 8) as u8, b: 7, a: 255 })
+///     .collect();
+///
+/// let mut parallel = vec![Gray::<f32>::default(); frame.len()];
+/// par_convert_slice(&frame, &mut parallel).unwrap();
+///
+/// let mut serial = vec![Gray::<f32>::default(); frame.len()];
+/// convert_slice(&frame, &mut serial).unwrap();
+///
+/// assert_eq!(parallel, serial);
+/// ```
+#[cfg(feature = "libm")]
+pub fn par_convert_slice<P, Q>(
+    source: &[P],
+    destination: &mut [Q],
+) -> Result<(), LengthMismatchError>
+where
+    P: Copy + Sync,
+    Q: FromPixelCommon<P> + Send,
+{
+    LengthMismatchError::check(source.len(), destination.len())?;
+    let chunk_len = chunk_len(core::mem::size_of::<P>().max(core::mem::size_of::<Q>()));
+    source
+        .par_chunks(chunk_len)
+        .zip(destination.par_chunks_mut(chunk_len))
+        .for_each(|(source, destination)| {
+            Q::from_pixel_common_slice(source, destination).unwrap();
+        });
+    Ok(())
+}
+
+/// Maps each component of each pixel in `source` with `f` into the same position in
+/// `destination` using [`Pixel::map_components()`], in parallel.
+///
+/// # Errors
+///
+/// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices are not
+/// the same length.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let pixels = vec![Rgb::<u8> { r: 1, g: 2, b: 3 }; 100_000];
+/// let mut wide = vec![Rgb::<u32>::default(); pixels.len()];
+///
+/// par_map_components(&pixels, &mut wide, |c| u32::from(c) * 1000).unwrap();
+/// assert!(wide.iter().all(|p| *p == Rgb { r: 1000, g: 2000, b: 3000 }));
+/// ```
+pub fn par_map_components<P, U>(
+    source: &[P],
+    destination: &mut [P::SelfType<U>],
+    f: impl Fn(P::Component) -> U + Sync,
+) -> Result<(), LengthMismatchError>
+where
+    P: Pixel + Sync,
+    U: PixelComponent,
+    P::SelfType<U>: Send,
+{
+    LengthMismatchError::check(source.len(), destination.len())?;
+    let chunk_len =
+        chunk_len(core::mem::size_of::<P>().max(core::mem::size_of::<P::SelfType<U>>()));
+    source
+        .par_chunks(chunk_len)
+        .zip(destination.par_chunks_mut(chunk_len))
+        .for_each(|(source, destination)| {
+            for (source, destination) in source.iter().zip(destination) {
+                *destination = source.map_components(&f);
+            }
+        });
+    Ok(())
+}
+
+/// Maps the color components of each pixel in `pixels` with `f` using [`Pixel::map_colors()`],
+/// in parallel.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let mut pixels = vec![Rgba::<u8> { r: 10, g: 20, b: 30, a: 40 }; 100_000];
+///
+/// par_map_colors(&mut pixels, |c| c * 2);
+/// assert!(pixels.iter().all(|p| *p == Rgba { r: 20, g: 40, b: 60, a: 40 }));
+/// ```
+pub fn par_map_colors<P>(pixels: &mut [P], f: impl Fn(P::Component) -> P::Component + Sync)
+where
+    P: Pixel + Send,
+{
+    pixels
+        .par_chunks_mut(chunk_len(core::mem::size_of::<P>()))
+        .for_each(|pixels| {
+            for pixel in pixels {
+                *pixel = pixel.map_colors(&f);
+            }
+        });
+}
+
+/// Maps the alpha component of each pixel in `pixels` with `f` using [`Pixel::map_alpha()`], in
+/// parallel.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let mut pixels = vec![Rgba::<u8> { r: 10, g: 20, b: 30, a: 40 }; 100_000];
+///
+/// par_map_alpha(&mut pixels, |a| a / 2);
+/// assert!(pixels.iter().all(|p| *p == Rgba { r: 10, g: 20, b: 30, a: 20 }));
+/// ```
+pub fn par_map_alpha<P>(pixels: &mut [P], f: impl Fn(P::Component) -> P::Component + Sync)
+where
+    P: Pixel + Send,
+{
+    pixels
+        .par_chunks_mut(chunk_len(core::mem::size_of::<P>()))
+        .for_each(|pixels| {
+            for pixel in pixels {
+                *pixel = pixel.map_alpha(&f);
+            }
+        });
+}
@@ -1,11 +1,232 @@
+use super::pixel_component::{
+    components_from_bytes, components_from_bytes_mut, components_to_bytes, components_to_bytes_mut,
+};
 use crate::*;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// An trait for converting to pixel from a slice.
+///
+/// A slice of contiguous pixels is also a contiguous slice of their components, so slices can be
+/// cast between the two without copying.
+///
+/// Pixels whose components are [`PodComponent`]s can also be cast to and from bytes.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let data = [255_u8, 0, 0, 255, 0, 255, 0, 255];
+///
+/// let pixels = Rgba::<u8>::slice_from_components(&data).unwrap();
+/// assert_eq!(pixels[1], Rgba { r: 0, g: 255, b: 0, a: 255 });
+/// assert_eq!(Rgba::slice_to_components(pixels), data);
+///
+/// assert_eq!(
+///     Rgba::<u8>::slice_from_components(&data[..7]),
+///     Err(CastError::SizeMismatch)
+/// );
+/// ```
 pub trait ContiguousPixel: Pixel {
     /// Returns a reference to the pixel type from a slice with the same number of components.
     fn from_component_slice_ref(slice: &[Self::Component]) -> &Self;
     /// Returns a mutable reference to the pixel type from a slice with the same number of components.
     fn from_component_slice_mut(slice: &mut [Self::Component]) -> &mut Self;
+
+    /// Casts a slice of components to a slice of pixels without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CastError::SizeMismatch`] if the length of `components` is not a multiple of
+    /// [`Pixel::COMPONENT_COUNT`].
+    fn slice_from_components(components: &[Self::Component]) -> Result<&[Self], CastError>;
+    /// Casts a mutable slice of components to a mutable slice of pixels without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CastError::SizeMismatch`] if the length of `components` is not a multiple of
+    /// [`Pixel::COMPONENT_COUNT`].
+    fn slice_from_components_mut(
+        components: &mut [Self::Component],
+    ) -> Result<&mut [Self], CastError>;
+    /// Casts a slice of pixels to a slice of their components without copying.
+    fn slice_to_components(pixels: &[Self]) -> &[Self::Component];
+    /// Casts a mutable slice of pixels to a mutable slice of their components without copying.
+    fn slice_to_components_mut(pixels: &mut [Self]) -> &mut [Self::Component];
+
+    /// Converts a vector of components to a vector of pixels.
+    ///
+    /// The provided implementation copies the pixels into a new vector, while the pixel types in
+    /// this crate reuse the allocation of `components`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CastError::SizeMismatch`] along with `components` if its length is not a
+    /// multiple of [`Pixel::COMPONENT_COUNT`]. Pixel types which reuse the allocation also
+    /// require its capacity to be a multiple.
+    #[cfg(feature = "alloc")]
+    fn vec_from_components(
+        components: Vec<Self::Component>,
+    ) -> Result<Vec<Self>, (CastError, Vec<Self::Component>)> {
+        match Self::slice_from_components(&components) {
+            Ok(pixels) => Ok(pixels.to_vec()),
+            Err(error) => Err((error, components)),
+        }
+    }
+    /// Converts a vector of pixels to a vector of their components.
+    ///
+    /// The provided implementation copies the components into a new vector, while the pixel
+    /// types in this crate reuse the allocation of `pixels`.
+    #[cfg(feature = "alloc")]
+    fn vec_to_components(pixels: Vec<Self>) -> Vec<Self::Component> {
+        Self::slice_to_components(&pixels).to_vec()
+    }
+
+    /// Casts a slice of bytes to a slice of pixels without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CastError`] if the length of `bytes` is not a multiple of the size of the
+    /// pixel or `bytes` is not aligned for the pixel.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let bytes = [255_u8, 0, 0, 255, 0, 255, 0, 255];
+    /// let pixels = Rgba::<u8>::slice_from_bytes(&bytes).unwrap();
+    ///
+    /// assert_eq!(pixels[1], Rgba { r: 0, g: 255, b: 0, a: 255 });
+    /// assert_eq!(Rgba::slice_to_bytes(pixels), bytes);
+    ///
+    /// assert_eq!(Rgba::<u8>::slice_from_bytes(&bytes[..7]), Err(CastError::SizeMismatch));
+    ///
+    /// let pixels = [Gray::<u16> { gray: 1 }, Gray::<u16> { gray: 2 }];
+    /// let bytes = Gray::slice_to_bytes(&pixels);
+    /// assert_eq!(Gray::<u16>::slice_from_bytes(bytes), Ok(pixels.as_slice()));
+    /// assert_eq!(
+    ///     Gray::<u16>::slice_from_bytes(&bytes[1..3]),
+    ///     Err(CastError::AlignmentMismatch)
+    /// );
+    /// ```
+    fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], CastError>
+    where
+        Self::Component: PodComponent,
+    {
+        Self::slice_from_components(components_from_bytes(bytes)?)
+    }
+    /// Casts a mutable slice of bytes to a mutable slice of pixels without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CastError`] if the length of `bytes` is not a multiple of the size of the
+    /// pixel or `bytes` is not aligned for the pixel.
+    fn slice_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut [Self], CastError>
+    where
+        Self::Component: PodComponent,
+    {
+        Self::slice_from_components_mut(components_from_bytes_mut(bytes)?)
+    }
+    /// Casts a slice of pixels to a slice of their bytes without copying.
+    fn slice_to_bytes(pixels: &[Self]) -> &[u8]
+    where
+        Self::Component: PodComponent,
+    {
+        components_to_bytes(Self::slice_to_components(pixels))
+    }
+    /// Casts a mutable slice of pixels to a mutable slice of their bytes without copying.
+    fn slice_to_bytes_mut(pixels: &mut [Self]) -> &mut [u8]
+    where
+        Self::Component: PodComponent,
+    {
+        components_to_bytes_mut(Self::slice_to_components_mut(pixels))
+    }
+
+    /// Converts a vector of bytes to a vector of pixels, reusing its allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CastError`] along with `bytes` if its length or capacity is not a multiple of
+    /// the size of the pixel or the pixel needs a greater alignment than `u8`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use pixeli::*;
+    ///
+    /// let pixels = Rgb::<u8>::vec_from_bytes(vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(pixels[1], Rgb { r: 4, g: 5, b: 6 });
+    /// assert_eq!(Rgb::vec_to_bytes(pixels).unwrap(), [1, 2, 3, 4, 5, 6]);
+    ///
+    /// let (error, _) = Gray::<u16>::vec_from_bytes(vec![0; 4]).unwrap_err();
+    /// assert_eq!(error, CastError::AlignmentMismatch);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn vec_from_bytes(bytes: Vec<u8>) -> Result<Vec<Self>, (CastError, Vec<u8>)>
+    where
+        Self::Component: PodComponent,
+    {
+        let size = core::mem::size_of::<Self::Component>();
+        if core::mem::align_of::<Self::Component>() != 1 {
+            return Err((CastError::AlignmentMismatch, bytes));
+        }
+        if !bytes.len().is_multiple_of(size) || !bytes.capacity().is_multiple_of(size) {
+            return Err((CastError::SizeMismatch, bytes));
+        }
+
+        //This is safe since the component has the size and alignment checked above, so the
+        //allocation has the same layout, and is valid for any bytes.
+        let mut bytes = core::mem::ManuallyDrop::new(bytes);
+        let components = unsafe {
+            Vec::from_raw_parts(
+                bytes.as_mut_ptr().cast(),
+                bytes.len() / size,
+                bytes.capacity() / size,
+            )
+        };
+        Self::vec_from_components(components)
+            .map_err(|(error, components)| (error, components_to_byte_vec(components)))
+    }
+    /// Converts a vector of pixels to a vector of their bytes, reusing its allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CastError`] along with `pixels` if the pixel needs a greater alignment than
+    /// `u8`.
+    #[cfg(feature = "alloc")]
+    fn vec_to_bytes(pixels: Vec<Self>) -> Result<Vec<u8>, (CastError, Vec<Self>)>
+    where
+        Self::Component: PodComponent,
+    {
+        if core::mem::align_of::<Self::Component>() != 1 {
+            return Err((CastError::AlignmentMismatch, pixels));
+        }
+        Ok(components_to_byte_vec(Self::vec_to_components(pixels)))
+    }
+}
+
+/// Converts a vector of components with an alignment of one to a vector of their bytes.
+#[cfg(feature = "alloc")]
+fn components_to_byte_vec<T>(components: Vec<T>) -> Vec<u8>
+where
+    T: PodComponent,
+{
+    debug_assert_eq!(core::mem::align_of::<T>(), 1);
+    let size = core::mem::size_of::<T>();
+
+    //This is safe since `T` has no padding bytes and the same alignment as `u8`, so the
+    //allocation has the same layout.
+    let mut components = core::mem::ManuallyDrop::new(components);
+    unsafe {
+        Vec::from_raw_parts(
+            components.as_mut_ptr().cast(),
+            components.len() * size,
+            components.capacity() * size,
+        )
+    }
 }
 
 macro_rules! implement_contiguous_pixel {
@@ -28,11 +249,62 @@ macro_rules! implement_contiguous_pixel {
                 );
                 unsafe { &mut *(slice.as_mut_ptr() as *mut $pixel<T>) }
             }
+
+            fn slice_from_components(components: &[Self::Component]) -> Result<&[Self], CastError> {
+                let len = pixel_count::<Self>(components.len())?;
+                Ok(unsafe { core::slice::from_raw_parts(components.as_ptr() as *const Self, len) })
+            }
+            fn slice_from_components_mut(
+                components: &mut [Self::Component],
+            ) -> Result<&mut [Self], CastError> {
+                let len = pixel_count::<Self>(components.len())?;
+                Ok(unsafe {
+                    core::slice::from_raw_parts_mut(components.as_mut_ptr() as *mut Self, len)
+                })
+            }
+            fn slice_to_components(pixels: &[Self]) -> &[Self::Component] {
+                let len = pixels.len() * usize::from(Self::COMPONENT_COUNT);
+                unsafe { core::slice::from_raw_parts(pixels.as_ptr() as *const T, len) }
+            }
+            fn slice_to_components_mut(pixels: &mut [Self]) -> &mut [Self::Component] {
+                let len = pixels.len() * usize::from(Self::COMPONENT_COUNT);
+                unsafe { core::slice::from_raw_parts_mut(pixels.as_mut_ptr() as *mut T, len) }
+            }
+
+            #[cfg(feature = "alloc")]
+            fn vec_from_components(
+                components: Vec<Self::Component>,
+            ) -> Result<Vec<Self>, (CastError, Vec<Self::Component>)> {
+                let (len, capacity) = match (
+                    pixel_count::<Self>(components.len()),
+                    pixel_count::<Self>(components.capacity()),
+                ) {
+                    (Ok(len), Ok(capacity)) => (len, capacity),
+                    (Err(error), _) | (_, Err(error)) => return Err((error, components)),
+                };
+                let mut components = core::mem::ManuallyDrop::new(components);
+                Ok(unsafe {
+                    Vec::from_raw_parts(components.as_mut_ptr() as *mut Self, len, capacity)
+                })
+            }
+            #[cfg(feature = "alloc")]
+            fn vec_to_components(pixels: Vec<Self>) -> Vec<Self::Component> {
+                let count = usize::from(Self::COMPONENT_COUNT);
+                let mut pixels = core::mem::ManuallyDrop::new(pixels);
+                unsafe {
+                    Vec::from_raw_parts(
+                        pixels.as_mut_ptr() as *mut T,
+                        pixels.len() * count,
+                        pixels.capacity() * count,
+                    )
+                }
+            }
         }
     };
 }
 //This is safe since we use #[repr(C)] for the pixel struct definitions to ensure contiguous
-//layout.
+//layout, and since every field has the same type the pixel has the size of its components with
+//the alignment of one component.
 implement_contiguous_pixel!(Rgb);
 implement_contiguous_pixel!(Bgr);
 implement_contiguous_pixel!(Rgba);
@@ -41,3 +313,97 @@ implement_contiguous_pixel!(Bgra);
 implement_contiguous_pixel!(Abgr);
 implement_contiguous_pixel!(Gray);
 implement_contiguous_pixel!(GrayAlpha);
+implement_contiguous_pixel!(Hsl);
+implement_contiguous_pixel!(Hsla);
+implement_contiguous_pixel!(Hsv);
+implement_contiguous_pixel!(Hsva);
+implement_contiguous_pixel!(Hwb);
+implement_contiguous_pixel!(Hwba);
+implement_contiguous_pixel!(Xyz);
+implement_contiguous_pixel!(Xyza);
+implement_contiguous_pixel!(Lab);
+implement_contiguous_pixel!(Laba);
+implement_contiguous_pixel!(Lch);
+implement_contiguous_pixel!(Lcha);
+implement_contiguous_pixel!(Oklab);
+implement_contiguous_pixel!(Oklaba);
+implement_contiguous_pixel!(Oklch);
+implement_contiguous_pixel!(Oklcha);
+implement_contiguous_pixel!(YCbCr);
+implement_contiguous_pixel!(YCbCrA);
+implement_contiguous_pixel!(Indexed);
+
+/// Returns the number of pixels in `component_count` components.
+fn pixel_count<P>(component_count: usize) -> Result<usize, CastError>
+where
+    P: Pixel,
+{
+    let count = usize::from(P::COMPONENT_COUNT);
+    if component_count.is_multiple_of(count) {
+        Ok(component_count / count)
+    } else {
+        Err(CastError::SizeMismatch)
+    }
+}
+
+macro_rules! implement_contiguous_pixel_wrapper {
+    ($wrapper:ident) => {
+        impl<P> ContiguousPixel for $wrapper<P>
+        where
+            P: ContiguousPixel,
+        {
+            fn from_component_slice_ref(slice: &[Self::Component]) -> &Self {
+                let pixel = P::from_component_slice_ref(slice);
+                unsafe { &*(pixel as *const P as *const $wrapper<P>) }
+            }
+            fn from_component_slice_mut(slice: &mut [Self::Component]) -> &mut Self {
+                let pixel = P::from_component_slice_mut(slice);
+                unsafe { &mut *(pixel as *mut P as *mut $wrapper<P>) }
+            }
+
+            fn slice_from_components(components: &[Self::Component]) -> Result<&[Self], CastError> {
+                let pixels = P::slice_from_components(components)?;
+                Ok(unsafe { &*(pixels as *const [P] as *const [$wrapper<P>]) })
+            }
+            fn slice_from_components_mut(
+                components: &mut [Self::Component],
+            ) -> Result<&mut [Self], CastError> {
+                let pixels = P::slice_from_components_mut(components)?;
+                Ok(unsafe { &mut *(pixels as *mut [P] as *mut [$wrapper<P>]) })
+            }
+            fn slice_to_components(pixels: &[Self]) -> &[Self::Component] {
+                P::slice_to_components(unsafe { &*(pixels as *const [$wrapper<P>] as *const [P]) })
+            }
+            fn slice_to_components_mut(pixels: &mut [Self]) -> &mut [Self::Component] {
+                P::slice_to_components_mut(unsafe {
+                    &mut *(pixels as *mut [$wrapper<P>] as *mut [P])
+                })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn vec_from_components(
+                components: Vec<Self::Component>,
+            ) -> Result<Vec<Self>, (CastError, Vec<Self::Component>)> {
+                let pixels = core::mem::ManuallyDrop::new(P::vec_from_components(components)?);
+                Ok(unsafe {
+                    Vec::from_raw_parts(
+                        pixels.as_ptr() as *mut $wrapper<P>,
+                        pixels.len(),
+                        pixels.capacity(),
+                    )
+                })
+            }
+            #[cfg(feature = "alloc")]
+            fn vec_to_components(pixels: Vec<Self>) -> Vec<Self::Component> {
+                let pixels = core::mem::ManuallyDrop::new(pixels);
+                P::vec_to_components(unsafe {
+                    Vec::from_raw_parts(pixels.as_ptr() as *mut P, pixels.len(), pixels.capacity())
+                })
+            }
+        }
+    };
+}
+//This is safe since we use #[repr(transparent)] for the pixel wrapper definitions to ensure the
+//same layout as the wrapped pixel.
+implement_contiguous_pixel_wrapper!(Premultiplied);
+implement_contiguous_pixel_wrapper!(Linear);
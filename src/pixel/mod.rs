@@ -1,10 +1,35 @@
 use crate::AsSlice;
+use crate::FromComponentsError;
 use crate::PixelComponent;
 
 pub mod as_slice;
 pub mod contiguous_pixel;
 pub mod pixel_component;
 
+/// Identifies the pixel and component types with vectorized slice conversions.
+///
+/// These types cannot be named outside of this crate, so the hidden trait methods returning them
+/// cannot be overridden there and can be trusted to identify a type for `unsafe` casts.
+pub(crate) mod sealed {
+    /// The layout of a pixel type.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum Layout {
+        Rgba,
+        Bgra,
+        Argb,
+        Abgr,
+        Gray,
+        Other,
+    }
+    /// A component type.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum Component {
+        U8,
+        F32,
+        Other,
+    }
+}
+
 /// A Pixel made up of a compile-time known number of contiguously stored `T`s.
 ///
 /// Pixels can optionally contain a single alpha component.
@@ -61,6 +86,60 @@ pub trait Pixel: Copy {
         alpha: Self::Component,
     ) -> Self;
 
+    /// Creates a new instance given an iterator of exactly as many components as the pixel has.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromComponentsError`] if the iterator produces too few or too many components.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// assert_eq!(Rgb::try_from_components([1, 2, 3]), Ok(Rgb { r: 1, g: 2, b: 3 }));
+    /// assert_eq!(
+    ///     Rgb::<u8>::try_from_components([1, 2]),
+    ///     Err(FromComponentsError::TooFew { expected: 3, actual: 2 })
+    /// );
+    /// assert_eq!(
+    ///     Rgb::<u8>::try_from_components(0..),
+    ///     Err(FromComponentsError::TooMany { expected: 3 })
+    /// );
+    /// ```
+    fn try_from_components(
+        components: impl IntoIterator<Item = Self::Component>,
+    ) -> Result<Self, FromComponentsError> {
+        try_from_iterator(components, |components| Self::from_components(components))
+    }
+
+    /// Creates a new instance given an iterator of exactly as many color components as the pixel
+    /// has and an alpha component.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromComponentsError`] counting only color components if the iterator produces
+    /// too few or too many color components.
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// assert_eq!(
+    ///     Rgba::try_from_colors_alpha([1, 2, 3], 4),
+    ///     Ok(Rgba { r: 1, g: 2, b: 3, a: 4 })
+    /// );
+    /// assert_eq!(
+    ///     Rgba::try_from_colors_alpha([1, 2, 3, 4], 5),
+    ///     Err(FromComponentsError::TooMany { expected: 3 })
+    /// );
+    /// ```
+    fn try_from_colors_alpha(
+        colors: impl IntoIterator<Item = Self::Component>,
+        alpha: Self::Component,
+    ) -> Result<Self, FromComponentsError> {
+        try_from_iterator(colors, |colors| Self::from_colors_alpha(colors, alpha))
+    }
+
     /// Maps each of the pixels components with a function `f`.
     fn map_components<U>(&self, f: impl FnMut(Self::Component) -> U) -> Self::SelfType<U>
     where
@@ -73,10 +152,80 @@ pub trait Pixel: Copy {
     ///
     /// If the pixel has no alpha component then the pixel is returned unchanged.
     fn map_alpha(&self, f: impl FnMut(Self::Component) -> Self::Component) -> Self;
+
+    /// Creates a pixel of type `Q` whose components are the components of `self` at the given
+    /// `indices`.
+    ///
+    /// The indices follow the component order of `self` and are listed in the component order of
+    /// `Q`, so any reordering is possible and components can be repeated or dropped.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any index is not less than [`Pixel::COMPONENT_COUNT`].
+    ///
+    /// # Examples
+    /// ```
+    /// use pixeli::*;
+    ///
+    /// let rgba = Rgba::<u8> { r: 1, g: 2, b: 3, a: 4 };
+    ///
+    /// let gbra: Rgba<u8> = rgba.swizzle([1, 2, 0, 3]);
+    /// assert_eq!(gbra, Rgba { r: 2, g: 3, b: 1, a: 4 });
+    ///
+    /// let bgr: Bgr<u8> = rgba.swizzle([2, 1, 0]);
+    /// assert_eq!(bgr, Bgr { b: 3, g: 2, r: 1 });
+    ///
+    /// let alpha: Rgb<u8> = rgba.swizzle([3, 3, 3]);
+    /// assert_eq!(alpha, Rgb { r: 4, g: 4, b: 4 });
+    ///
+    /// let gray = Gray::<u8> { gray: 9 };
+    /// let rgba: Rgba<u8> = gray.swizzle([0, 0, 0, 0]);
+    /// assert_eq!(rgba, Rgba { r: 9, g: 9, b: 9, a: 9 });
+    /// ```
+    fn swizzle<Q>(&self, indices: Q::ComponentArray<usize>) -> Q
+    where
+        Q: Pixel<Component = Self::Component>,
+    {
+        let components = self.component_array();
+        let components = components.as_slice();
+        Q::from_components(indices.into_iter().map(|index| components[index]))
+    }
+
+    #[doc(hidden)]
+    fn sealed_layout() -> sealed::Layout {
+        sealed::Layout::Other
+    }
+}
+
+/// Calls `from_iterator` with an iterator over `components` which produces zeros once
+/// `components` runs out, then checks how many of `components` it used.
+fn try_from_iterator<T, P>(
+    components: impl IntoIterator<Item = T>,
+    from_iterator: impl FnOnce(&mut dyn Iterator<Item = T>) -> P,
+) -> Result<P, FromComponentsError>
+where
+    T: PixelComponent,
+{
+    let mut components = components.into_iter().fuse();
+    let mut expected = 0;
+    let mut actual = 0;
+    let pixel = from_iterator(&mut core::iter::from_fn(|| {
+        expected += 1;
+        let component = components.next().inspect(|_| actual += 1);
+        Some(component.unwrap_or(T::zero()))
+    }));
+
+    if actual < expected {
+        Err(FromComponentsError::TooFew { expected, actual })
+    } else if components.next().is_some() {
+        Err(FromComponentsError::TooMany { expected })
+    } else {
+        Ok(pixel)
+    }
 }
 
 macro_rules! implement_pixel_without_alpha {
-    ($name:ident, $length:literal, [$($bit:ident),*]) => {
+    ($name:ident, $length:literal, [$($bit:ident),*] $(, $layout:ident)?) => {
         impl<T> Pixel for $name<T>
         where
             T: PixelComponent,
@@ -127,12 +276,18 @@ macro_rules! implement_pixel_without_alpha {
             {
                 *self
             }
+
+            $(
+                fn sealed_layout() -> crate::pixel::sealed::Layout {
+                    crate::pixel::sealed::Layout::$layout
+                }
+            )?
         }
     }
 }
 
 macro_rules! implement_pixel_with_alpha {
-    ($name:tt, $length:literal, [$($bit:ident),*], [$($color_bit:ident),*], $alpha_bit:ident) => {
+    ($name:tt, $length:literal, [$($bit:ident),*], [$($color_bit:ident),*], $alpha_bit:ident $(, $layout:ident)?) => {
         impl<T> Pixel for $name<T>
         where
             T: PixelComponent,
@@ -183,25 +338,83 @@ macro_rules! implement_pixel_with_alpha {
             {
                 Self::SelfType::from_colors_alpha(self.color_array(), f(self.$alpha_bit))
             }
+
+            $(
+                fn sealed_layout() -> crate::pixel::sealed::Layout {
+                    crate::pixel::sealed::Layout::$layout
+                }
+            )?
         }
     }
 }
 
+macro_rules! implement_pixel_wrapper {
+    ($name:ident) => {
+        impl<P> Pixel for $name<P>
+        where
+            P: Pixel,
+        {
+            type Component = P::Component;
+
+            const COMPONENT_COUNT: u8 = P::COMPONENT_COUNT;
+
+            type SelfType<U: PixelComponent> = $name<P::SelfType<U>>;
+            type ComponentArray<R> = P::ComponentArray<R>;
+            type ColorArray<R> = P::ColorArray<R>;
+
+            fn component_array(&self) -> Self::ComponentArray<Self::Component> {
+                self.0.component_array()
+            }
+            fn color_array(&self) -> Self::ColorArray<Self::Component> {
+                self.0.color_array()
+            }
+            fn alpha(&self) -> Option<Self::Component> {
+                self.0.alpha()
+            }
+
+            fn from_components(components: impl IntoIterator<Item = Self::Component>) -> Self {
+                $name(P::from_components(components))
+            }
+            fn from_colors_alpha(
+                colors: impl IntoIterator<Item = Self::Component>,
+                alpha: Self::Component,
+            ) -> Self {
+                $name(P::from_colors_alpha(colors, alpha))
+            }
+
+            fn map_components<U>(&self, f: impl FnMut(Self::Component) -> U) -> Self::SelfType<U>
+            where
+                U: PixelComponent,
+            {
+                $name(self.0.map_components(f))
+            }
+
+            fn map_colors(&self, f: impl FnMut(Self::Component) -> Self::Component) -> Self {
+                $name(self.0.map_colors(f))
+            }
+
+            fn map_alpha(&self, f: impl FnMut(Self::Component) -> Self::Component) -> Self {
+                $name(self.0.map_alpha(f))
+            }
+        }
+    };
+}
+
 mod rgba {
     use crate::*;
-    implement_pixel_with_alpha!(Rgba, 4, [r, g, b, a], [r, g, b], a);
+    implement_pixel_with_alpha!(Rgba, 4, [r, g, b, a], [r, g, b], a, Rgba);
 }
 mod abgr {
     use crate::*;
-    implement_pixel_with_alpha!(Abgr, 4, [a, b, g, r], [b, g, r], a);
+    implement_pixel_with_alpha!(Abgr, 4, [a, b, g, r], [b, g, r], a, Abgr);
 }
 mod argb {
     use crate::*;
-    implement_pixel_with_alpha!(Argb, 4, [a, r, g, b], [r, g, b], a);
+    implement_pixel_with_alpha!(Argb, 4, [a, r, g, b], [r, g, b], a, Argb);
 }
 mod bgra {
     use crate::*;
-    implement_pixel_with_alpha!(Bgra, 4, [b, g, r, a], [b, g, r], a);
+    implement_pixel_with_alpha!(Bgra, 4, [b, g, r, a], [b, g, r], a, Bgra);
 }
 mod gray_alpha {
     use crate::*;
@@ -210,7 +423,7 @@ mod gray_alpha {
 
 mod gray {
     use crate::*;
-    implement_pixel_without_alpha!(Gray, 1, [gray]);
+    implement_pixel_without_alpha!(Gray, 1, [gray], Gray);
 }
 mod bgr {
     use crate::*;
@@ -220,3 +433,88 @@ mod rgb {
     use crate::*;
     implement_pixel_without_alpha!(Rgb, 3, [r, g, b]);
 }
+mod hsla {
+    use crate::*;
+    implement_pixel_with_alpha!(Hsla, 4, [h, s, l, a], [h, s, l], a);
+}
+mod hsva {
+    use crate::*;
+    implement_pixel_with_alpha!(Hsva, 4, [h, s, v, a], [h, s, v], a);
+}
+mod hwba {
+    use crate::*;
+    implement_pixel_with_alpha!(Hwba, 4, [h, w, b, a], [h, w, b], a);
+}
+mod hsl {
+    use crate::*;
+    implement_pixel_without_alpha!(Hsl, 3, [h, s, l]);
+}
+mod hsv {
+    use crate::*;
+    implement_pixel_without_alpha!(Hsv, 3, [h, s, v]);
+}
+mod hwb {
+    use crate::*;
+    implement_pixel_without_alpha!(Hwb, 3, [h, w, b]);
+}
+mod xyza {
+    use crate::*;
+    implement_pixel_with_alpha!(Xyza, 4, [x, y, z, a], [x, y, z], a);
+}
+mod laba {
+    use crate::*;
+    implement_pixel_with_alpha!(Laba, 4, [l, a, b, alpha], [l, a, b], alpha);
+}
+mod lcha {
+    use crate::*;
+    implement_pixel_with_alpha!(Lcha, 4, [l, c, h, a], [l, c, h], a);
+}
+mod oklaba {
+    use crate::*;
+    implement_pixel_with_alpha!(Oklaba, 4, [l, a, b, alpha], [l, a, b], alpha);
+}
+mod oklcha {
+    use crate::*;
+    implement_pixel_with_alpha!(Oklcha, 4, [l, c, h, a], [l, c, h], a);
+}
+mod xyz {
+    use crate::*;
+    implement_pixel_without_alpha!(Xyz, 3, [x, y, z]);
+}
+mod lab {
+    use crate::*;
+    implement_pixel_without_alpha!(Lab, 3, [l, a, b]);
+}
+mod lch {
+    use crate::*;
+    implement_pixel_without_alpha!(Lch, 3, [l, c, h]);
+}
+mod oklab {
+    use crate::*;
+    implement_pixel_without_alpha!(Oklab, 3, [l, a, b]);
+}
+mod oklch {
+    use crate::*;
+    implement_pixel_without_alpha!(Oklch, 3, [l, c, h]);
+}
+mod y_cb_cr_a {
+    use crate::*;
+    implement_pixel_with_alpha!(YCbCrA, 4, [y, cb, cr, a], [y, cb, cr], a);
+}
+mod y_cb_cr {
+    use crate::*;
+    implement_pixel_without_alpha!(YCbCr, 3, [y, cb, cr]);
+}
+mod indexed {
+    use crate::*;
+    implement_pixel_without_alpha!(Indexed, 1, [index]);
+}
+
+mod premultiplied {
+    use crate::*;
+    implement_pixel_wrapper!(Premultiplied);
+}
+mod linear {
+    use crate::*;
+    implement_pixel_wrapper!(Linear);
+}
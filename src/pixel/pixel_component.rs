@@ -1,30 +1,124 @@
 use num_traits::{Num, NumAssign, NumCast, NumOps};
 
+use super::sealed;
+use crate::{CastError, Snorm, Unorm};
+
 /// A trait for all the required super-traits for a pixel component type.
 pub trait PixelComponent: Copy + Num + NumCast + NumAssign + NumOps + PartialOrd<Self> {
     /// The minimum component value
     const COMPONENT_MIN: Self;
     /// The maximum component value
     const COMPONENT_MAX: Self;
+
+    #[doc(hidden)]
+    fn sealed_component() -> sealed::Component {
+        sealed::Component::Other
+    }
+}
+
+/// A pixel component which is plain old data, so slices of it can be cast to and from bytes.
+///
+/// This is implemented for every primitive integer and float, [`Unorm`], [`Snorm`] and, with the
+/// `half` feature, `f16` and `bf16`. It is what allows the byte casts of
+/// [`ContiguousPixel`](crate::ContiguousPixel), which do not need the `bytemuck` feature.
+///
+/// # Safety
+///
+/// The type must not be zero-sized, must have no padding bytes and every bit pattern of its size
+/// must be a valid value, so that its bytes can be read as `u8`s and any aligned bytes can be read
+/// as the type.
+pub unsafe trait PodComponent: PixelComponent {}
+
+/// Casts a slice of bytes to a slice of components.
+pub(crate) fn components_from_bytes<T>(bytes: &[u8]) -> Result<&[T], CastError>
+where
+    T: PodComponent,
+{
+    //This is safe since `T` is valid for any bytes.
+    let (prefix, components, suffix) = unsafe { bytes.align_to::<T>() };
+    check_cast(prefix, suffix)?;
+    Ok(components)
+}
+/// Casts a mutable slice of bytes to a mutable slice of components.
+pub(crate) fn components_from_bytes_mut<T>(bytes: &mut [u8]) -> Result<&mut [T], CastError>
+where
+    T: PodComponent,
+{
+    //This is safe since `T` is valid for any bytes, and any `T` written is valid bytes.
+    let (prefix, components, suffix) = unsafe { bytes.align_to_mut::<T>() };
+    check_cast(prefix, suffix)?;
+    Ok(components)
+}
+/// Checks that casting bytes to components left no bytes before or after the components.
+fn check_cast(prefix: &[u8], suffix: &[u8]) -> Result<(), CastError> {
+    if !prefix.is_empty() {
+        Err(CastError::AlignmentMismatch)
+    } else if !suffix.is_empty() {
+        Err(CastError::SizeMismatch)
+    } else {
+        Ok(())
+    }
+}
+/// Casts a slice of components to a slice of their bytes.
+pub(crate) fn components_to_bytes<T>(components: &[T]) -> &[u8]
+where
+    T: PodComponent,
+{
+    //This is safe since `T` has no padding bytes.
+    unsafe {
+        core::slice::from_raw_parts(
+            components.as_ptr().cast(),
+            core::mem::size_of_val(components),
+        )
+    }
+}
+/// Casts a mutable slice of components to a mutable slice of their bytes.
+pub(crate) fn components_to_bytes_mut<T>(components: &mut [T]) -> &mut [u8]
+where
+    T: PodComponent,
+{
+    //This is safe since `T` has no padding bytes and is valid for any bytes written.
+    unsafe {
+        core::slice::from_raw_parts_mut(
+            components.as_mut_ptr().cast(),
+            core::mem::size_of_val(components),
+        )
+    }
 }
 
 macro_rules! implement_integer {
-    ($int:ident) => {
+    ($int:ident $(, $component:ident)?) => {
         impl PixelComponent for $int {
             const COMPONENT_MIN: Self = $int::MIN;
             const COMPONENT_MAX: Self = $int::MAX;
+
+            $(
+                fn sealed_component() -> sealed::Component {
+                    sealed::Component::$component
+                }
+            )?
         }
+        unsafe impl PodComponent for $int {}
     };
 }
 macro_rules! implement_float {
-    ($int:ident) => {
+    ($int:ident $(, $component:ident)?) => {
         impl PixelComponent for $int {
             const COMPONENT_MIN: Self = 0.0;
             const COMPONENT_MAX: Self = 1.0;
+
+            $(
+                fn sealed_component() -> sealed::Component {
+                    sealed::Component::$component
+                }
+            )?
         }
+        unsafe impl PodComponent for $int {}
     };
 }
-implement_integer!(u8);
+//This is safe since primitive integers and floats have no padding and no invalid values, and
+//`Unorm`, `Snorm` and the `half` types are `#[repr(transparent)]` wrappers of them.
+implement_integer!(u8, U8);
 implement_integer!(u16);
 implement_integer!(u32);
 implement_integer!(u64);
@@ -36,5 +130,48 @@ implement_integer!(i64);
 implement_integer!(i128);
 implement_integer!(usize);
 implement_integer!(isize);
-implement_float!(f32);
+implement_float!(f32, F32);
 implement_float!(f64);
+
+macro_rules! implement_unorm {
+    ($int:ident) => {
+        // Evaluating the maximum fails to compile if `BITS` is zero or wider than `$int`.
+        impl<const BITS: u32> PixelComponent for Unorm<$int, BITS> {
+            const COMPONENT_MIN: Self = Unorm(0);
+            const COMPONENT_MAX: Self = Unorm($int::MAX >> ($int::BITS - BITS));
+        }
+        unsafe impl<const BITS: u32> PodComponent for Unorm<$int, BITS> {}
+    };
+}
+macro_rules! implement_snorm {
+    ($int:ident) => {
+        impl PixelComponent for Snorm<$int> {
+            const COMPONENT_MIN: Self = Snorm(-$int::MAX);
+            const COMPONENT_MAX: Self = Snorm($int::MAX);
+        }
+        unsafe impl PodComponent for Snorm<$int> {}
+    };
+}
+implement_unorm!(u8);
+implement_unorm!(u16);
+implement_unorm!(u32);
+implement_unorm!(u64);
+implement_snorm!(i8);
+implement_snorm!(i16);
+implement_snorm!(i32);
+implement_snorm!(i64);
+
+#[cfg(feature = "half")]
+macro_rules! implement_half {
+    ($half:ident) => {
+        impl PixelComponent for half::$half {
+            const COMPONENT_MIN: Self = half::$half::ZERO;
+            const COMPONENT_MAX: Self = half::$half::ONE;
+        }
+        unsafe impl PodComponent for half::$half {}
+    };
+}
+#[cfg(feature = "half")]
+implement_half!(f16);
+#[cfg(feature = "half")]
+implement_half!(bf16);
@@ -0,0 +1,62 @@
+// `bytemuck` trait implementations, which let pixels be cast to and from bytes.
+//
+// This is safe since the pixels are `#[repr(C)]` structs whose fields all have the same type, or
+// `#[repr(transparent)]` wrappers, so they have no padding when their components are `Pod`.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::*;
+
+macro_rules! implement_pod {
+    ($($pixel:ident),*) => {
+        $(
+            unsafe impl<T: Zeroable> Zeroable for $pixel<T> {}
+            unsafe impl<T: Pod> Pod for $pixel<T> {}
+        )*
+    };
+}
+macro_rules! implement_pod_newtype {
+    ($($newtype:ident),*) => {
+        $(
+            unsafe impl Zeroable for $newtype {}
+            unsafe impl Pod for $newtype {}
+        )*
+    };
+}
+
+implement_pod!(
+    Rgb,
+    Bgr,
+    Rgba,
+    Argb,
+    Bgra,
+    Abgr,
+    Gray,
+    GrayAlpha,
+    Hsl,
+    Hsla,
+    Hsv,
+    Hsva,
+    Hwb,
+    Hwba,
+    Xyz,
+    Xyza,
+    Lab,
+    Laba,
+    Lch,
+    Lcha,
+    Oklab,
+    Oklaba,
+    Oklch,
+    Oklcha,
+    YCbCr,
+    YCbCrA,
+    Indexed,
+    Premultiplied,
+    Linear,
+    Snorm
+);
+implement_pod_newtype!(Rgb565, Bgr565, Rgba4444, Argb1555, Rgba5551, Rgb10a2);
+
+unsafe impl<T: Zeroable, const BITS: u32> Zeroable for Unorm<T, BITS> {}
+unsafe impl<T: Pod, const BITS: u32> Pod for Unorm<T, BITS> {}
@@ -0,0 +1,118 @@
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A pixel whose color components have already been multiplied by its alpha component.
+///
+/// Wrapping a pixel does not change its components, so use [`Premultiplied::premultiply()`] to
+/// premultiply a straight-alpha pixel, or construct `Premultiplied(pixel)` directly if `pixel` is
+/// already premultiplied.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let straight = Rgba::<u8> { r: 255, g: 100, b: 0, a: 128 };
+/// let premultiplied = Premultiplied::premultiply(straight);
+///
+/// assert_eq!(premultiplied, Premultiplied(Rgba { r: 128, g: 50, b: 0, a: 128 }));
+/// assert_eq!(premultiplied.unpremultiply(), Rgba { r: 255, g: 100, b: 0, a: 128 });
+/// ```
+pub struct Premultiplied<P>(pub P);
+
+#[cfg(feature = "libm")]
+mod premultiply {
+    use crate::from_pixel_common::enlargeable::{divide, multiply};
+    use crate::from_pixel_common::simd;
+    use crate::*;
+
+    impl<P> Premultiplied<P>
+    where
+        P: Pixel,
+        P::Component: Enlargeable,
+    {
+        /// Premultiplies the color components of a straight-alpha pixel by its alpha component.
+        ///
+        /// Integer components are rounded to the nearest value. Pixels without an alpha
+        /// component are left unchanged.
+        pub fn premultiply(pixel: P) -> Self {
+            match pixel.alpha() {
+                Some(alpha) => Premultiplied(pixel.map_colors(|color| multiply(color, alpha))),
+                None => Premultiplied(pixel),
+            }
+        }
+
+        /// Divides the color components by the alpha component to get back a straight-alpha
+        /// pixel.
+        ///
+        /// Integer components are rounded to the nearest value. If the alpha component is zero
+        /// then the color components are set to their minimum value, since the original color
+        /// cannot be recovered.
+        pub fn unpremultiply(self) -> P {
+            match self.0.alpha() {
+                Some(alpha) if alpha == P::Component::COMPONENT_MIN => {
+                    self.0.map_colors(|_| P::Component::COMPONENT_MIN)
+                }
+                Some(alpha) => self.0.map_colors(|color| divide(color, alpha)),
+                None => self.0,
+            }
+        }
+
+        /// Premultiplies each pixel in `source` into the same position in `destination`.
+        ///
+        /// The result is identical to calling [`Premultiplied::premultiply()`] on each pixel, but
+        /// is vectorized for four component `u8` pixels.
+        ///
+        /// # Errors
+        ///
+        /// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices
+        /// are not the same length.
+        ///
+        /// # Examples
+        /// ```
+        /// use pixeli::*;
+        ///
+        /// let straight: Vec<_> = (0..=255)
+        ///     .map(|i| Rgba::<u8> { r: i, g: 255 - i, b: 100, a: i.wrapping_mul(31) })
+        ///     .collect();
+        /// let mut premultiplied = vec![Premultiplied::default(); straight.len()];
+        /// Premultiplied::premultiply_slice(&straight, &mut premultiplied).unwrap();
+        ///
+        /// for (straight, premultiplied) in straight.iter().zip(&premultiplied) {
+        ///     assert_eq!(*premultiplied, Premultiplied::premultiply(*straight));
+        /// }
+        /// ```
+        pub fn premultiply_slice(
+            source: &[P],
+            destination: &mut [Self],
+        ) -> Result<(), LengthMismatchError> {
+            LengthMismatchError::check(source.len(), destination.len())?;
+            let vectorized = simd::premultiply(source, destination);
+            for (source, destination) in source[vectorized..]
+                .iter()
+                .zip(&mut destination[vectorized..])
+            {
+                *destination = Self::premultiply(*source);
+            }
+            Ok(())
+        }
+
+        /// Unpremultiplies each pixel in `source` into the same position in `destination`.
+        ///
+        /// The result is identical to calling [`Premultiplied::unpremultiply()`] on each pixel.
+        ///
+        /// # Errors
+        ///
+        /// Returns a [`LengthMismatchError`] and leaves `destination` untouched if the two slices
+        /// are not the same length.
+        pub fn unpremultiply_slice(
+            source: &[Self],
+            destination: &mut [P],
+        ) -> Result<(), LengthMismatchError> {
+            LengthMismatchError::check(source.len(), destination.len())?;
+            for (source, destination) in source.iter().zip(destination) {
+                *destination = source.unpremultiply();
+            }
+            Ok(())
+        }
+    }
+}
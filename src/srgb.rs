@@ -0,0 +1,163 @@
+use core::sync::atomic::{AtomicBool, AtomicU16, AtomicU8, Ordering};
+
+use crate::*;
+
+/// A pixel component which can be converted between the sRGB transfer function and linear light.
+///
+/// Floats use the exact piecewise sRGB formula, `u8` and `u16` use lookup tables computed from it
+/// the first time they are needed, and all other integers use the exact formula via `f64`.
+pub trait SrgbComponent: PixelComponent {
+    /// Decodes an sRGB encoded component into linear light.
+    fn srgb_to_linear(self) -> Self;
+    /// Encodes a linear light component with the sRGB transfer function.
+    fn linear_to_srgb(self) -> Self;
+}
+
+fn srgb_to_linear_f64(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        libm::pow((c + 0.055) / 1.055, 2.4)
+    }
+}
+fn linear_to_srgb_f64(l: f64) -> f64 {
+    if l <= 0.0031308 {
+        l * 12.92
+    } else {
+        1.055 * libm::pow(l, 1.0 / 2.4) - 0.055
+    }
+}
+fn srgb_to_linear_f32(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        libm::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+fn linear_to_srgb_f32(l: f32) -> f32 {
+    if l <= 0.0031308 {
+        l * 12.92
+    } else {
+        1.055 * libm::powf(l, 1.0 / 2.4) - 0.055
+    }
+}
+
+impl SrgbComponent for f32 {
+    fn srgb_to_linear(self) -> Self {
+        srgb_to_linear_f32(self)
+    }
+    fn linear_to_srgb(self) -> Self {
+        linear_to_srgb_f32(self)
+    }
+}
+impl SrgbComponent for f64 {
+    fn srgb_to_linear(self) -> Self {
+        srgb_to_linear_f64(self)
+    }
+    fn linear_to_srgb(self) -> Self {
+        linear_to_srgb_f64(self)
+    }
+}
+
+macro_rules! lookup_table {
+    ($name:ident, $int:ident, $atomic:ident, $len:literal) => {
+        /// A table of every value of an integer component passed through a transfer function.
+        ///
+        /// The table is filled on first use so that it costs nothing until needed. Threads which
+        /// race to fill it all store identical values so no locking is required.
+        struct $name {
+            filled: AtomicBool,
+            values: [$atomic; $len],
+            function: fn(f64) -> f64,
+        }
+        impl $name {
+            const fn new(function: fn(f64) -> f64) -> Self {
+                Self {
+                    filled: AtomicBool::new(false),
+                    values: [const { $atomic::new(0) }; $len],
+                    function,
+                }
+            }
+
+            fn get(&self, component: $int) -> $int {
+                if !self.filled.load(Ordering::Acquire) {
+                    for (i, value) in self.values.iter().enumerate() {
+                        let i = i as $int;
+                        let mapped = $int::from_component_common((self.function)(
+                            f64::from_component_common(i),
+                        ));
+                        value.store(mapped, Ordering::Relaxed);
+                    }
+                    self.filled.store(true, Ordering::Release);
+                }
+                self.values[usize::from(component)].load(Ordering::Relaxed)
+            }
+        }
+    };
+}
+lookup_table!(U8Table, u8, AtomicU8, 256);
+lookup_table!(U16Table, u16, AtomicU16, 65536);
+
+static U8_SRGB_TO_LINEAR: U8Table = U8Table::new(srgb_to_linear_f64);
+static U8_LINEAR_TO_SRGB: U8Table = U8Table::new(linear_to_srgb_f64);
+static U16_SRGB_TO_LINEAR: U16Table = U16Table::new(srgb_to_linear_f64);
+static U16_LINEAR_TO_SRGB: U16Table = U16Table::new(linear_to_srgb_f64);
+
+impl SrgbComponent for u8 {
+    fn srgb_to_linear(self) -> Self {
+        U8_SRGB_TO_LINEAR.get(self)
+    }
+    fn linear_to_srgb(self) -> Self {
+        U8_LINEAR_TO_SRGB.get(self)
+    }
+}
+impl SrgbComponent for u16 {
+    fn srgb_to_linear(self) -> Self {
+        U16_SRGB_TO_LINEAR.get(self)
+    }
+    fn linear_to_srgb(self) -> Self {
+        U16_LINEAR_TO_SRGB.get(self)
+    }
+}
+
+macro_rules! via_f64 {
+    ($int:ident) => {
+        impl SrgbComponent for $int {
+            fn srgb_to_linear(self) -> Self {
+                Self::from_component_common(srgb_to_linear_f64(f64::from_component_common(self)))
+            }
+            fn linear_to_srgb(self) -> Self {
+                Self::from_component_common(linear_to_srgb_f64(f64::from_component_common(self)))
+            }
+        }
+    };
+}
+via_f64!(u32);
+via_f64!(u64);
+via_f64!(u128);
+via_f64!(i8);
+via_f64!(i16);
+via_f64!(i32);
+via_f64!(i64);
+via_f64!(i128);
+via_f64!(usize);
+via_f64!(isize);
+
+impl<P> Linear<P>
+where
+    P: Pixel<Component: SrgbComponent>,
+{
+    /// Decodes the color components of an sRGB encoded pixel into linear light.
+    ///
+    /// The alpha component, if any, is left unchanged since it is always linear.
+    pub fn from_srgb(pixel: P) -> Self {
+        Linear(pixel.map_colors(SrgbComponent::srgb_to_linear))
+    }
+
+    /// Encodes the color components with the sRGB transfer function.
+    ///
+    /// The alpha component, if any, is left unchanged since it is always linear.
+    pub fn to_srgb(self) -> P {
+        self.0.map_colors(SrgbComponent::linear_to_srgb)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::*;
+
+/// Reorders the components of each pixel in a buffer of `N` component pixels in place, such that
+/// component `i` of each pixel becomes its old component `indices[i]`.
+///
+/// This is the slice equivalent of [`Pixel::swizzle()`](crate::Pixel::swizzle) for when the
+/// input and output pixels have the same number of components, such as converting a `BGRA`
+/// buffer from a decoder into `RGBA` without copying it. Use
+/// [`ContiguousPixel`](crate::ContiguousPixel) to cast between pixels and components.
+///
+/// `N` must not be zero, which is checked at compile time:
+/// ```compile_fail
+/// pixeli::swizzle_slice::<u8, 0>(&mut [], []);
+/// ```
+///
+/// Red and blue swaps of four component `u8` pixels, such as between `BGRA` and `RGBA`, are
+/// vectorized.
+///
+/// Returns a [`CastError`] if the length of `components` is not a multiple of `N`.
+///
+/// # Panics
+///
+/// This function will panic if any index is not less than `N`.
+///
+/// # Examples
+/// ```
+/// use pixeli::*;
+///
+/// let mut buffer = [3_u8, 2, 1, 255, 6, 5, 4, 128];
+///
+/// swizzle_slice(&mut buffer, [2, 1, 0, 3]).unwrap();
+///
+/// let pixels = Rgba::slice_from_components(&buffer).unwrap();
+/// assert_eq!(pixels[0], Rgba { r: 1, g: 2, b: 3, a: 255 });
+/// assert_eq!(pixels[1], Rgba { r: 4, g: 5, b: 6, a: 128 });
+///
+/// let mut pixels = [Rgb::<u8> { r: 1, g: 2, b: 3 }; 2];
+/// swizzle_slice(Rgb::slice_to_components_mut(&mut pixels), [1, 1, 0]).unwrap();
+/// assert_eq!(pixels, [Rgb { r: 2, g: 2, b: 1 }; 2]);
+///
+/// assert_eq!(swizzle_slice(&mut [1_u8, 2, 3], [1, 0]), Err(CastError::SizeMismatch));
+/// ```
+#[inline]
+pub fn swizzle_slice<T, const N: usize>(
+    components: &mut [T],
+    indices: [usize; N],
+) -> Result<(), CastError>
+where
+    T: PixelComponent,
+{
+    const { assert!(N > 0, "swizzle_slice needs at least one component") };
+    assert!(
+        indices.iter().all(|index| *index < N),
+        "swizzle indices must be less than the number of components"
+    );
+    if !components.len().is_multiple_of(N) {
+        return Err(CastError::SizeMismatch);
+    }
+
+    // Three and four component pixels are by far the most common, so they get their own loops
+    // over fixed size chunks which the compiler can unroll.
+    match *indices.as_slice() {
+        [a, b, c] => swizzle_chunks(components, [a, b, c]),
+        [a, b, c, d] => {
+            #[cfg(feature = "libm")]
+            let components = {
+                let vectorized = match [a, b, c, d] {
+                    [2, 1, 0, 3] => crate::from_pixel_common::simd::swap_in_place(components, 0),
+                    [0, 3, 2, 1] => crate::from_pixel_common::simd::swap_in_place(components, 1),
+                    _ => 0,
+                };
+                &mut components[vectorized..]
+            };
+            swizzle_chunks(components, [a, b, c, d])
+        }
+        _ => swizzle_chunks(components, indices),
+    }
+    Ok(())
+}
+
+/// Reorders the components of each `N` component chunk of `components`, ignoring any remainder.
+fn swizzle_chunks<T, const N: usize>(components: &mut [T], indices: [usize; N])
+where
+    T: Copy,
+{
+    for pixel in components.as_chunks_mut::<N>().0 {
+        *pixel = indices.map(|index| pixel[index]);
+    }
+}
@@ -21,13 +21,16 @@ pub trait WithoutAlpha: Pixel {
 
 macro_rules! implement_lower_upper {
     ($lower:ident, $upper:ident, {$($bit:ident),*}) => {
+        implement_lower_upper!($lower, $upper, {$($bit),*}, a);
+    };
+    ($lower:ident, $upper:ident, {$($bit:ident),*}, $alpha_bit:ident) => {
         impl<T> WithAlpha for $lower<T> where T: PixelComponent {
             type WithAlpha = $upper<T>;
 
             fn with_alpha(self) -> Self::WithAlpha {
                 $upper {
                     $($bit: self.$bit),*,
-                    a: <$lower<T> as Pixel>::Component::COMPONENT_MAX,
+                    $alpha_bit: <$lower<T> as Pixel>::Component::COMPONENT_MAX,
                 }
             }
         }
@@ -74,13 +77,71 @@ macro_rules! implement_without_no_op {
 implement_without_no_op!(Rgb);
 implement_without_no_op!(Bgr);
 implement_without_no_op!(Gray);
+implement_without_no_op!(Hsl);
+implement_without_no_op!(Hsv);
+implement_without_no_op!(Hwb);
+implement_without_no_op!(Xyz);
+implement_without_no_op!(Lab);
+implement_without_no_op!(Lch);
+implement_without_no_op!(Oklab);
+implement_without_no_op!(Oklch);
+implement_without_no_op!(YCbCr);
+implement_without_no_op!(Indexed);
 
 implement_with_no_op!(Rgba);
 implement_with_no_op!(Argb);
 implement_with_no_op!(Bgra);
 implement_with_no_op!(Abgr);
 implement_with_no_op!(GrayAlpha);
+implement_with_no_op!(Hsla);
+implement_with_no_op!(Hsva);
+implement_with_no_op!(Hwba);
+implement_with_no_op!(Xyza);
+implement_with_no_op!(Laba);
+implement_with_no_op!(Lcha);
+implement_with_no_op!(Oklaba);
+implement_with_no_op!(Oklcha);
+implement_with_no_op!(YCbCrA);
 
 implement_lower_upper!(Rgb, Rgba, {r, g, b});
 implement_lower_upper!(Bgr, Bgra, {r, g, b});
 implement_lower_upper!(Gray, GrayAlpha, { gray });
+implement_lower_upper!(Hsl, Hsla, {h, s, l});
+implement_lower_upper!(Hsv, Hsva, {h, s, v});
+implement_lower_upper!(Hwb, Hwba, {h, w, b});
+implement_lower_upper!(Xyz, Xyza, {x, y, z});
+implement_lower_upper!(Lab, Laba, {l, a, b}, alpha);
+implement_lower_upper!(Lch, Lcha, {l, c, h});
+implement_lower_upper!(Oklab, Oklaba, {l, a, b}, alpha);
+implement_lower_upper!(Oklch, Oklcha, {l, c, h});
+implement_lower_upper!(YCbCr, YCbCrA, {y, cb, cr});
+
+// For premultiplied pixels, adding an alpha component at its maximum value leaves the colors
+// unchanged, and removing it gives the colors as they would appear composited over black.
+macro_rules! implement_wrapper {
+    ($wrapper:ident) => {
+        impl<P> WithAlpha for $wrapper<P>
+        where
+            P: WithAlpha,
+        {
+            type WithAlpha = $wrapper<P::WithAlpha>;
+
+            fn with_alpha(self) -> Self::WithAlpha {
+                $wrapper(self.0.with_alpha())
+            }
+        }
+        impl<P> WithoutAlpha for $wrapper<P>
+        where
+            P: WithoutAlpha,
+        {
+            type WithoutAlpha = $wrapper<P::WithoutAlpha>;
+
+            fn without_alpha(self) -> Self::WithoutAlpha {
+                $wrapper(self.0.without_alpha())
+            }
+        }
+    };
+}
+
+implement_wrapper!(Premultiplied);
+implement_wrapper!(Linear);
@@ -0,0 +1,12 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A CIE `XYZ` pixel.
+pub struct Xyz<T> {
+    /// X Component
+    pub x: T,
+    /// Y Component, the luminance
+    pub y: T,
+    /// Z Component
+    pub z: T,
+}
@@ -0,0 +1,14 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A CIE `XYZ` pixel with an alpha component.
+pub struct Xyza<T> {
+    /// X Component
+    pub x: T,
+    /// Y Component, the luminance
+    pub y: T,
+    /// Z Component
+    pub z: T,
+    /// Alpha Component
+    pub a: T,
+}
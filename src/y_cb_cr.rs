@@ -0,0 +1,12 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A `Y'CbCr` pixel.
+pub struct YCbCr<T> {
+    /// Luma Component
+    pub y: T,
+    /// Blue-Difference Chroma Component
+    pub cb: T,
+    /// Red-Difference Chroma Component
+    pub cr: T,
+}
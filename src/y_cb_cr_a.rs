@@ -0,0 +1,14 @@
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// A `Y'CbCr` pixel with an alpha component.
+pub struct YCbCrA<T> {
+    /// Luma Component
+    pub y: T,
+    /// Blue-Difference Chroma Component
+    pub cb: T,
+    /// Red-Difference Chroma Component
+    pub cr: T,
+    /// Alpha Component
+    pub a: T,
+}